@@ -0,0 +1,100 @@
+//! Derive macros for the [`ocm`](https://docs.rs/ocm) crate. These are re-exported from `ocm`
+//! when its `derive` feature is enabled, so this crate should not usually be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, Member, PathArguments, Type};
+
+/// Derives `ErrorCollector` for a struct with a `Vec<E>` field marked `#[errors]`.
+/// 
+/// See the documentation for `ErrorCollector` in the `ocm` crate for details.
+#[proc_macro_derive(ErrorCollector, attributes(errors))]
+pub fn derive_error_collector(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(input.ident.span(), "`ErrorCollector` can only be derived for structs"));
+    };
+
+    let (member, error_ty) = find_errors_field(&data.fields, &input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::ocm::ErrorCollector<#error_ty> for #name #ty_generics #where_clause {
+            type WrappedInner = Self;
+
+            fn push_error(&mut self, error: #error_ty) {
+                self.#member.push(error);
+            }
+
+            fn errors(&self) -> ::core::option::Option<&[#error_ty]> {
+                ::core::option::Option::Some(&self.#member)
+            }
+
+            fn for_each_error(&self, f: &mut dyn ::core::ops::FnMut(&#error_ty)) {
+                self.#member.iter().for_each(f);
+            }
+
+            fn propagate(
+                mut self,
+                other: &mut (impl ::ocm::ErrorCollector<#error_ty> + ?Sized),
+            ) -> Self::WrappedInner {
+                for error in ::core::mem::take(&mut self.#member) {
+                    other.push_error(error);
+                }
+                self
+            }
+
+            fn drain_into(&mut self, other: &mut dyn ::ocm::ErrorCollector<#error_ty>) {
+                for error in self.#member.drain(..) {
+                    other.push_error(error);
+                }
+            }
+        }
+    })
+}
+
+/// Finds the single field marked `#[errors]`, returning how to access it and its error type.
+fn find_errors_field<'a>(fields: &'a Fields, input: &DeriveInput) -> syn::Result<(Member, &'a Type)> {
+    let mut marked = fields.iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path().is_ident("errors")));
+
+    let Some((index, field)) = marked.next() else {
+        return Err(Error::new(input.ident.span(), "expected a field marked with `#[errors]`"));
+    };
+    if let Some((_, other)) = marked.next() {
+        return Err(Error::new(other.span(), "only one field can be marked with `#[errors]`"));
+    }
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+    Ok((member, vec_element_type(&field.ty)?))
+}
+
+/// Extracts `E` from a field type of `Vec<E>`.
+fn vec_element_type(ty: &Type) -> syn::Result<&Type> {
+    let error = || Error::new(ty.span(), "the `#[errors]` field must have type `Vec<E>`");
+
+    let Type::Path(path) = ty else { return Err(error()) };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != "Vec" {
+        return Err(error());
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return Err(error()) };
+    match args.args.first() {
+        Some(GenericArgument::Type(element)) if args.args.len() == 1 => Ok(element),
+        _ => Err(error()),
+    }
+}
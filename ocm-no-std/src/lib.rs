@@ -0,0 +1,36 @@
+//! Checks that `ocm` builds and works without the standard library.
+//! 
+//! Test this crate on its own, with `cargo test -p ocm-no-std`. When the whole workspace is built,
+//! Cargo unifies features, so `ocm` would be built with `std` anyway.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use ocm::{ErrorCollector, ErrorSentinel, Outcome};
+
+/// Parses a comma-separated list of numbers, skipping and collecting any which are invalid.
+pub fn parse_numbers(input: &str) -> Outcome<Vec<u32>, String> {
+    let mut errors = ErrorSentinel::empty();
+    let numbers = input
+        .split(',')
+        .filter_map(|part| match part.trim().parse() {
+            Ok(n) => Some(n),
+            Err(_) => {
+                errors.push_error(alloc::format!("invalid number: {}", part.trim()));
+                None
+            }
+        })
+        .collect();
+    errors.into_outcome(numbers)
+}
+
+/// Sums the numbers in each input, propagating every parse error into `errors`.
+pub fn sum_all(inputs: &[&str], errors: &mut impl ErrorCollector<String>) -> u32 {
+    inputs
+        .iter()
+        .map(|input| parse_numbers(input).propagate(errors).into_iter().sum::<u32>())
+        .sum()
+}
@@ -0,0 +1,31 @@
+use ocm::{ErrorCollector, ErrorSentinel};
+use ocm_no_std::{parse_numbers, sum_all};
+
+#[test]
+fn outcome_without_errors() {
+    let o = parse_numbers("1, 2, 3");
+    assert!(!o.has_errors());
+    assert_eq!(o.unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn outcome_with_errors() {
+    let (numbers, errors) = parse_numbers("1, x, 3, y").finalize();
+    assert_eq!(numbers, vec![1, 3]);
+    assert_eq!(errors.handle(|errs| errs), vec!["invalid number: x", "invalid number: y"]);
+}
+
+#[test]
+fn propagate_into_sentinel() {
+    let mut errors = ErrorSentinel::empty();
+    assert_eq!(sum_all(&["1, 2", "oops, 3", "4"], &mut errors), 10);
+    assert_eq!(errors.error_count(), 1);
+    assert_eq!(errors.handle(|errs| errs), vec!["invalid number: oops"]);
+}
+
+#[test]
+#[should_panic(expected = "dropped without handling errors")]
+fn unhandled_sentinel_panics() {
+    let (_, errors) = parse_numbers("nope").finalize();
+    drop(errors);
+}
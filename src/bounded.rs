@@ -0,0 +1,140 @@
+use crate::ErrorCollector;
+
+/// An [`ErrorCollector`] which wraps another collector, retaining at most a maximum number of
+/// errors.
+/// 
+/// Once the inner collector holds the maximum number of errors, any further errors pushed into the
+/// `BoundedCollector` are dropped, and [`truncated`] returns `true`. This bounds the memory used
+/// when processing input which could produce a huge number of errors. Use [`is_full`] to stop
+/// doing work early once no more errors will be retained.
+/// 
+/// The `BoundedCollector` counts the errors it has passed on itself. This means the bound applies
+/// even to collectors which don't keep count, like channel senders, and moving the errors out with
+/// [`drain_into`] doesn't make room for more.
+/// 
+/// [`truncated`]: BoundedCollector::truncated
+/// [`is_full`]: BoundedCollector::is_full
+/// [`drain_into`]: ErrorCollector::drain_into
+/// 
+/// ```
+/// # use ocm::{BoundedCollector, ErrorCollector, ErrorSentinel};
+/// let mut errors = BoundedCollector::new(ErrorSentinel::empty(), 2);
+/// for i in 0..5 {
+///     errors.push_error(i);
+/// }
+/// 
+/// assert!(errors.truncated());
+/// let errors = errors.into_inner();
+/// assert_eq!(errors.peek(), &[0, 1]);
+/// # errors.ignore();
+/// ```
+/// 
+/// ```
+/// # use ocm::{BoundedCollector, ErrorCollector, ErrorSentinel, SinkCollector};
+/// // A `SinkCollector` always reports having no errors, but the bound still applies
+/// let mut errors = BoundedCollector::new(SinkCollector, 2);
+/// for i in 0..10 {
+///     errors.push_error(i);
+/// }
+/// assert!(errors.truncated());
+/// 
+/// // Moving the retained errors out doesn't make room for more
+/// let mut errors = BoundedCollector::new(ErrorSentinel::empty(), 2);
+/// errors.push_error(0);
+/// errors.push_error(1);
+/// let mut drained = vec![];
+/// (&mut errors).propagate(&mut drained);
+/// assert_eq!(drained, vec![0, 1]);
+/// 
+/// errors.push_error(2);
+/// assert!(errors.truncated());
+/// # errors.into_inner().ignore();
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedCollector<C> {
+    inner: C,
+    max: usize,
+    accepted: usize,
+    truncated: bool,
+}
+
+impl<C> BoundedCollector<C> {
+    /// Wraps a collector, so that it retains at most `max` errors. This includes any errors which
+    /// the collector already holds.
+    #[must_use]
+    pub fn new(inner: C, max: usize) -> Self {
+        Self { inner, max, accepted: 0, truncated: false }
+    }
+
+    /// Returns `true` if any errors have been dropped because the maximum was reached.
+    #[must_use]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns `true` if the maximum number of errors has been reached, so any further errors will
+    /// be dropped.
+    /// 
+    /// ```
+    /// # use ocm::{BoundedCollector, ErrorCollector, ErrorSentinel};
+    /// let mut errors = BoundedCollector::new(ErrorSentinel::empty(), 1);
+    /// assert!(!errors.is_full());
+    /// 
+    /// errors.push_error("error 1");
+    /// assert!(errors.is_full());
+    /// assert!(!errors.truncated());
+    /// # errors.into_inner().ignore();
+    /// ```
+    #[must_use]
+    pub fn is_full<E>(&self) -> bool
+    where
+        C: ErrorCollector<E>,
+    {
+        self.accepted.max(self.inner.error_count()) >= self.max
+    }
+
+    /// The maximum number of errors which will be retained.
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Consumes this `BoundedCollector`, returning the inner collector.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<E, C: ErrorCollector<E>> ErrorCollector<E> for BoundedCollector<C> {
+    type WrappedInner = C::WrappedInner;
+
+    fn push_error(&mut self, error: E) {
+        if self.is_full() {
+            self.truncated = true;
+        } else {
+            self.inner.push_error(error);
+            self.accepted += 1;
+        }
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        self.inner.errors()
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.inner.for_each_error(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.inner.error_count()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.inner.propagate(other)
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.inner.drain_into(other);
+    }
+}
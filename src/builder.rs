@@ -0,0 +1,131 @@
+use crate::{ErrorCollector, ErrorSentinel, Outcome};
+
+/// Incrementally constructs an [`Outcome`], where the value may be set at any point rather than
+/// being known up-front.
+/// 
+/// This is an alternative to [`Outcome::build`] for code where the value is only discovered deep
+/// inside some other logic, while errors are produced throughout.
+/// 
+/// ```
+/// # use ocm::{OutcomeBuilder, ErrorCollector};
+/// let mut builder = OutcomeBuilder::new();
+/// builder.push_error("error 1");
+/// builder.set_value(42);
+/// builder.push_error("error 2");
+/// 
+/// let (value, errors) = builder.finish().unwrap().finalize();
+/// assert_eq!(value, 42);
+/// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+/// # errors.ignore();
+/// ```
+/// 
+/// The errors are held in an [`ErrorSentinel`], so an `OutcomeBuilder` must be finished with
+/// [`finish`] or [`finish_or`], otherwise it will panic when dropped.
+/// 
+/// [`finish`]: OutcomeBuilder::finish
+/// [`finish_or`]: OutcomeBuilder::finish_or
+pub struct OutcomeBuilder<T, E> {
+    value: Option<T>,
+    errors: ErrorSentinel<E>,
+}
+
+impl<T, E> OutcomeBuilder<T, E> {
+    /// Constructs a new `OutcomeBuilder` with no value and no errors.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            errors: ErrorSentinel::empty(),
+        }
+    }
+
+    /// Adds a new error to this builder.
+    pub fn push_error(&mut self, error: E) {
+        self.errors.push_error(error);
+    }
+
+    /// Sets the value of the `Outcome` being built, replacing any value which was set previously.
+    pub fn set_value(&mut self, value: T) {
+        self.value = Some(value);
+    }
+
+    /// Returns `true` if a value has been set with [`set_value`].
+    /// 
+    /// [`set_value`]: OutcomeBuilder::set_value
+    #[must_use]
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Consumes this builder to create an [`Outcome`].
+    /// 
+    /// If a value was never set, returns an `Err` with the errors which were accumulated, which
+    /// must still be handled.
+    /// 
+    /// ```
+    /// # use ocm::OutcomeBuilder;
+    /// let mut builder = OutcomeBuilder::<u32, _>::new();
+    /// builder.push_error("error 1");
+    /// 
+    /// let errors = builder.finish().unwrap_err();
+    /// assert_eq!(errors.peek(), &["error 1"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use = "if a value was not set, discarding the `Result` will panic immediately"]
+    pub fn finish(self) -> Result<Outcome<T, E>, ErrorSentinel<E>> {
+        match self.value {
+            Some(value) => Ok(self.errors.into_outcome(value)),
+            None => Err(self.errors),
+        }
+    }
+
+    /// Consumes this builder to create an [`Outcome`], using a default value if a value was never
+    /// set.
+    /// 
+    /// ```
+    /// # use ocm::OutcomeBuilder;
+    /// let mut builder = OutcomeBuilder::new();
+    /// builder.push_error("error 1");
+    /// 
+    /// let (value, errors) = builder.finish_or(0).finalize();
+    /// assert_eq!(value, 0);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn finish_or(self, default: T) -> Outcome<T, E> {
+        let value = self.value.unwrap_or(default);
+        self.errors.into_outcome(value)
+    }
+}
+
+impl<T, E> Default for OutcomeBuilder<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> ErrorCollector<E> for OutcomeBuilder<T, E> {
+    type WrappedInner = Option<T>;
+
+    fn push_error(&mut self, error: E) {
+        OutcomeBuilder::push_error(self, error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        Some(self.errors.peek())
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.errors.iter().for_each(f);
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.errors.propagate(other);
+        self.value
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.errors.drain_into(other);
+    }
+}
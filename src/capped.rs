@@ -0,0 +1,203 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+use crate::{ErrorCollector, ErrorSentinel};
+
+/// An [`ErrorCollector`] which stores at most a maximum number of errors, counting how many
+/// further errors were dropped.
+/// 
+/// This protects against pathological inputs which generate so many errors that storing them all
+/// would exhaust memory. Unlike [`BoundedCollector`], which can wrap any collector, a
+/// `CappedCollector` stores its errors itself, and can optionally add an error summarising the
+/// dropped errors when it is [`finish`]ed.
+/// 
+/// [`BoundedCollector`]: crate::BoundedCollector
+/// [`finish`]: CappedCollector::finish
+/// 
+/// ```
+/// # use ocm::{CappedCollector, ErrorCollector};
+/// let mut errors = CappedCollector::new(3);
+/// for i in 0..3 {
+///     errors.push_error(i);
+/// }
+/// assert_eq!(errors.dropped(), 0);
+/// 
+/// errors.push_error(3);
+/// assert_eq!(errors.dropped(), 1);
+/// 
+/// let (errors, dropped) = errors.finish();
+/// assert_eq!(errors.peek(), &[0, 1, 2]);
+/// assert_eq!(dropped, 1);
+/// # errors.ignore();
+/// ```
+/// 
+/// # Panics
+/// 
+/// The stored errors are kept in an [`ErrorSentinel`], so like an `ErrorSentinel`, a
+/// `CappedCollector` must be handled before it is dropped - by calling [`finish`], or by
+/// propagating it. Dropping it otherwise panics, even if no errors were pushed.
+/// 
+/// ```should_panic
+/// # use ocm::{CappedCollector, ErrorCollector};
+/// let mut errors = CappedCollector::new(3);
+/// errors.push_error("oh no!");
+/// drop(errors); // Panics!
+/// ```
+pub struct CappedCollector<E> {
+    errors: ErrorSentinel<E>,
+    max: usize,
+    kept: usize,
+    dropped: usize,
+    overflow_error: Option<Box<dyn FnOnce(usize) -> E + Send>>,
+}
+
+impl<E> CappedCollector<E> {
+    /// Creates a new, empty `CappedCollector` which stores at most `max` errors.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self { errors: ErrorSentinel::empty(), max, kept: 0, dropped: 0, overflow_error: None }
+    }
+
+    /// Sets a function which creates an extra error summarising the dropped errors, given how many
+    /// there were. When [`finish`] is called, if any errors were dropped, the result is added after
+    /// the stored errors. The returned sentinel may then hold `max + 1` errors.
+    /// 
+    /// The function must be [`Send`], so that the collector can still be sent to another thread.
+    /// 
+    /// [`finish`]: CappedCollector::finish
+    /// 
+    /// ```
+    /// # use ocm::{CappedCollector, ErrorCollector};
+    /// let mut errors = CappedCollector::new(2)
+    ///     .with_overflow_error(|n| format!("{n} further error(s) suppressed"));
+    /// let mut errors = std::thread::spawn(move || {
+    ///     for i in 0..5 {
+    ///         errors.push_error(format!("error {i}"));
+    ///     }
+    ///     errors
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// 
+    /// let (errors, dropped) = errors.finish();
+    /// assert_eq!(dropped, 3);
+    /// assert_eq!(errors.peek(), &["error 0", "error 1", "3 further error(s) suppressed"]);
+    /// # errors.ignore();
+    /// 
+    /// // No errors were dropped, so no summary is added
+    /// let mut errors = CappedCollector::new(2)
+    ///     .with_overflow_error(|n| format!("{n} further error(s) suppressed"));
+    /// errors.push_error("error 0".to_owned());
+    /// errors.push_error("error 1".to_owned());
+    /// 
+    /// let (errors, dropped) = errors.finish();
+    /// assert_eq!(dropped, 0);
+    /// assert_eq!(errors.peek(), &["error 0", "error 1"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn with_overflow_error(mut self, func: impl FnOnce(usize) -> E + Send + 'static) -> Self {
+        self.overflow_error = Some(Box::new(func));
+        self
+    }
+
+    /// The number of errors which have been dropped because the maximum was reached.
+    #[must_use]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The maximum number of errors which will be stored. This applies over the collector's whole
+    /// life, so errors which were moved out with [`drain_into`] still count towards it.
+    /// 
+    /// [`drain_into`]: ErrorCollector::drain_into
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Consumes this `CappedCollector`, returning the stored errors and the number of errors which
+    /// were dropped.
+    /// 
+    /// If an overflow error was set with [`with_overflow_error`] and any errors were dropped, the
+    /// overflow error is added after the stored errors.
+    /// 
+    /// [`with_overflow_error`]: CappedCollector::with_overflow_error
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn finish(mut self) -> (ErrorSentinel<E>, usize) {
+        if self.dropped > 0 {
+            if let Some(func) = self.overflow_error.take() {
+                self.errors.push_error(func(self.dropped));
+            }
+        }
+        (self.errors, self.dropped)
+    }
+}
+
+impl<E: Debug> Debug for CappedCollector<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CappedCollector")
+            .field("errors", &self.errors)
+            .field("max", &self.max)
+            .field("dropped", &self.dropped)
+            .field("overflow_error", &self.overflow_error.is_some())
+            .finish()
+    }
+}
+
+impl<E> ErrorCollector<E> for CappedCollector<E> {
+    type WrappedInner = usize;
+
+    fn push_error(&mut self, error: E) {
+        if self.kept < self.max {
+            self.errors.push_error(error);
+            self.kept += 1;
+        } else {
+            self.dropped += 1;
+        }
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        Some(self.errors.peek())
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.errors.iter().for_each(f);
+    }
+
+    /// Propagates the stored errors, including any overflow error, returning the number of errors
+    /// which were dropped.
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        let (errors, dropped) = self.finish();
+        errors.propagate(other);
+        dropped
+    }
+
+    /// Moves the stored errors into `other`. They still count towards the maximum, so the collector
+    /// won't store any more errors than it would have otherwise. The overflow error, if any, is only
+    /// added by [`finish`](CappedCollector::finish).
+    /// 
+    /// ```
+    /// # use ocm::{CappedCollector, ErrorCollector};
+    /// let mut capped = CappedCollector::new(2)
+    ///     .with_overflow_error(|n| format!("{n} suppressed"));
+    /// for i in 0..5 {
+    ///     capped.push_error(format!("e{i}"));
+    /// }
+    /// 
+    /// let mut errors = vec![];
+    /// (&mut capped).propagate(&mut errors);
+    /// assert_eq!(errors, vec!["e0", "e1"]);
+    /// 
+    /// for i in 5..8 {
+    ///     capped.push_error(format!("e{i}"));
+    /// }
+    /// let (rest, dropped) = capped.finish();
+    /// assert_eq!(dropped, 6);
+    /// assert_eq!(rest.peek(), &["6 suppressed"]);
+    /// # rest.ignore();
+    /// ```
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.errors.drain_into(other);
+    }
+}
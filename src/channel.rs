@@ -0,0 +1,163 @@
+use std::sync::mpsc::{Sender, SyncSender};
+
+use crate::ErrorCollector;
+
+/// A [`Sender`] can collect errors by sending each one through its channel as soon as it is
+/// pushed. This lets worker threads stream their errors to a central reporting thread.
+/// 
+/// # Panics
+/// 
+/// Pushing an error panics if the receiving end of the channel has been dropped. Silently
+/// discarding the error would defeat the purpose of this crate, and the only way for this to happen
+/// is for the reporting thread to stop early, which is a logic error.
+/// 
+/// A `Sender` has nowhere to keep track of the errors sent through it, so [`error_count`] is
+/// always zero and [`has_errors`] is always `false`, even after errors have been pushed. Generic
+/// code will treat the sender as clean. [`propagate`] has nothing to move either. The receiving end
+/// is responsible for handling the errors, and for noticing that there are any.
+/// 
+/// Requires the `std` feature.
+/// 
+/// [`error_count`]: ErrorCollector::error_count
+/// [`has_errors`]: ErrorCollector::has_errors
+/// [`propagate`]: ErrorCollector::propagate
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// use std::{sync::mpsc, thread};
+/// 
+/// let (sender, receiver) = mpsc::channel();
+/// let workers = (0..4)
+///     .map(|worker| {
+///         let mut sender = sender.clone();
+///         thread::spawn(move || {
+///             let o = Outcome::new_with_errors(worker, vec![format!("worker {worker}: error 1")]);
+///             let value = o.propagate(&mut sender);
+///             Outcome::new_with_errors((), vec![format!("worker {value}: error 2")]).propagate(&mut sender);
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// drop(sender);
+/// 
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// let errors = receiver.iter().collect::<Vec<_>>();
+/// assert_eq!(errors.len(), 8);
+/// assert!(errors.contains(&"worker 3: error 2".to_owned()));
+/// 
+/// // The senders themselves never report any errors
+/// let (mut sender, _receiver) = mpsc::channel();
+/// sender.push_error("sent");
+/// assert!(!sender.has_errors());
+/// ```
+/// 
+/// ```should_panic
+/// # use ocm::ErrorCollector;
+/// use std::sync::mpsc;
+/// 
+/// let (mut sender, receiver) = mpsc::channel();
+/// drop(receiver);
+/// sender.push_error("nobody is listening"); // Panics!
+/// ```
+impl<E> ErrorCollector<E> for Sender<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        if self.send(error).is_err() {
+            panic!("error channel disconnected: receiver was dropped before all errors were sent");
+        }
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
+
+/// A [`SyncSender`] can collect errors by sending each one through its channel, blocking if the
+/// channel's buffer is full.
+/// 
+/// This behaves the same as the implementation for [`Sender`], including panicking if the receiving
+/// end of the channel has been dropped, and always having an
+/// [`error_count`](ErrorCollector::error_count) of zero.
+/// 
+/// Requires the `std` feature.
+/// 
+/// ```
+/// # use ocm::Outcome;
+/// use std::{sync::mpsc, thread};
+/// 
+/// let (mut sender, receiver) = mpsc::sync_channel(1);
+/// let reporter = thread::spawn(move || receiver.iter().count());
+/// 
+/// Outcome::new_with_errors((), vec!["error 1", "error 2", "error 3"]).propagate(&mut sender);
+/// drop(sender);
+/// assert_eq!(reporter.join().unwrap(), 3);
+/// ```
+impl<E> ErrorCollector<E> for SyncSender<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        if self.send(error).is_err() {
+            panic!("error channel disconnected: receiver was dropped before all errors were sent");
+        }
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
+
+/// A [`crossbeam_channel::Sender`] can collect errors by sending each one through its channel as
+/// soon as it is pushed. This is a natural place to collect errors from `rayon` or `crossbeam`
+/// workers.
+/// 
+/// If the channel is bounded and full, pushing an error blocks until there is space, so no errors
+/// are ever lost. Like the implementation for [`std::sync::mpsc::Sender`], pushing an error panics
+/// if the receiving end of the channel has been dropped, and the sender can't count the errors sent
+/// through it, so its [`error_count`](ErrorCollector::error_count) is always zero.
+/// 
+/// Requires the `crossbeam` feature.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// use std::thread;
+/// 
+/// // A tiny buffer, so that senders regularly have to wait for the receiver
+/// let (sender, receiver) = crossbeam_channel::bounded(1);
+/// let reporter = thread::spawn(move || receiver.iter().count());
+/// 
+/// thread::scope(|scope| {
+///     for worker in 0..4 {
+///         let mut sender = sender.clone();
+///         scope.spawn(move || {
+///             let errors = (0..100).map(|i| (worker, i)).collect();
+///             Outcome::new_with_errors((), errors).propagate(&mut sender);
+///         });
+///     }
+/// });
+/// assert!(!sender.has_errors());
+/// drop(sender);
+/// 
+/// assert_eq!(reporter.join().unwrap(), 400);
+/// ```
+#[cfg(feature = "crossbeam")]
+impl<E> ErrorCollector<E> for crossbeam_channel::Sender<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        if self.send(error).is_err() {
+            panic!("error channel disconnected: receiver was dropped before all errors were sent");
+        }
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
@@ -0,0 +1,150 @@
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt::{Display, Write};
+
+use crate::{ErrorSentinel, Outcome};
+
+/// An error type where each error has a stable code, like `E0042`.
+/// 
+/// Implementing this trait enables code-based queries on [`Outcome`] and [`ErrorSentinel`], such as
+/// [`has_code`] and [`count_by_code`]. Asserting on codes rather than messages keeps tests stable
+/// when the wording of a message changes.
+/// 
+/// [`has_code`]: Outcome::has_code
+/// [`count_by_code`]: Outcome::count_by_code
+/// 
+/// ```
+/// # use ocm::{ErrorCode, Outcome};
+/// enum Diagnostic {
+///     UnknownField(String),
+///     MissingField(String),
+/// }
+/// 
+/// impl ErrorCode for Diagnostic {
+///     fn code(&self) -> &str {
+///         match self {
+///             Diagnostic::UnknownField(_) => "E0001",
+///             Diagnostic::MissingField(_) => "E0002",
+///         }
+///     }
+/// }
+/// 
+/// // Allow unknown fields, but not missing ones
+/// let allowed = ["E0001"];
+/// let mut o = Outcome::new_with_errors((), vec![
+///     Diagnostic::UnknownField("colour".to_owned()),
+///     Diagnostic::MissingField("name".to_owned()),
+///     Diagnostic::UnknownField("size".to_owned()),
+/// ]);
+/// o.retain_errors(|e| !allowed.contains(&e.code()));
+/// 
+/// assert!(o.has_code("E0002"));
+/// assert!(!o.has_code("E0001"));
+/// # o.finalize().1.ignore();
+/// ```
+pub trait ErrorCode {
+    /// The stable code identifying what kind of error this is.
+    fn code(&self) -> &str;
+}
+
+/// Counts how many times each code appears.
+fn count_by_code<'a, E: ErrorCode + 'a>(errors: impl Iterator<Item = &'a E>) -> BTreeMap<&'a str, usize> {
+    let mut counts = BTreeMap::new();
+    for error in errors {
+        *counts.entry(error.code()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Builds a report with each error on its own line, prefixed by its code.
+fn coded_report<'a, E: ErrorCode + Display + 'a>(errors: impl Iterator<Item = &'a E>) -> String {
+    let mut report = String::new();
+    for (i, error) in errors.enumerate() {
+        if i > 0 {
+            report.push('\n');
+        }
+        // Writing to a `String` can't fail
+        let _ = write!(report, "[{}] {}", error.code(), error);
+    }
+    report
+}
+
+impl<T, E: ErrorCode> Outcome<T, E> {
+    /// Counts how many errors in this `Outcome` have each code. Codes are ordered alphabetically,
+    /// which makes the result convenient for stable assertions.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCode, Outcome};
+    /// # use std::collections::BTreeMap;
+    /// struct Coded(&'static str);
+    /// impl ErrorCode for Coded {
+    ///     fn code(&self) -> &str { self.0 }
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors((), vec![Coded("E0002"), Coded("E0001"), Coded("E0002")]);
+    /// assert_eq!(o.count_by_code(), BTreeMap::from([("E0001", 1), ("E0002", 2)]));
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn count_by_code(&self) -> BTreeMap<&str, usize> {
+        count_by_code(self.iter_errors())
+    }
+
+    /// Returns `true` if any error in this `Outcome` has the given code.
+    #[must_use]
+    pub fn has_code(&self, code: &str) -> bool {
+        self.iter_errors().any(|error| error.code() == code)
+    }
+
+    /// Builds a report with each error on its own line, prefixed by its code in square brackets.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCode, Outcome};
+    /// # use std::fmt::{self, Display};
+    /// struct Coded(&'static str, &'static str);
+    /// impl ErrorCode for Coded {
+    ///     fn code(&self) -> &str { self.0 }
+    /// }
+    /// impl Display for Coded {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.1) }
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors((), vec![
+    ///     Coded("E0042", "unknown field"),
+    ///     Coded("E0007", "missing field"),
+    /// ]);
+    /// assert_eq!(o.to_coded_report(), "[E0042] unknown field\n[E0007] missing field");
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn to_coded_report(&self) -> String
+    where
+        E: Display,
+    {
+        coded_report(self.iter_errors())
+    }
+}
+
+impl<E: ErrorCode> ErrorSentinel<E> {
+    /// Counts how many errors in this `ErrorSentinel` have each code. See
+    /// [`Outcome::count_by_code`] for details.
+    #[must_use]
+    pub fn count_by_code(&self) -> BTreeMap<&str, usize> {
+        count_by_code(self.iter())
+    }
+
+    /// Returns `true` if any error in this `ErrorSentinel` has the given code.
+    #[must_use]
+    pub fn has_code(&self, code: &str) -> bool {
+        self.iter().any(|error| error.code() == code)
+    }
+
+    /// Builds a report with each error on its own line, prefixed by its code in square brackets.
+    /// See [`Outcome::to_coded_report`] for details.
+    #[must_use]
+    pub fn to_coded_report(&self) -> String
+    where
+        E: Display,
+    {
+        coded_report(self.iter())
+    }
+}
@@ -1,19 +1,570 @@
+use alloc::{collections::{BTreeSet, BinaryHeap, LinkedList, VecDeque}, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{collections::HashSet, hash::Hash};
+
 /// Something which tracks a collection of errors.
 /// 
 /// This generalizes methods like [`ErrorSentinel::propagate`] which allow errors to be handled by
 /// merging them into a different collection of errors.
 /// 
 /// [`ErrorSentinel::propagate`]: crate::ErrorSentinel::propagate
+/// 
+/// This trait can be used as a trait object, `dyn ErrorCollector<E>`, to accept any kind of
+/// collector without being generic. [`propagate`] is not available on trait objects, but other
+/// collectors can still be propagated _into_ one.
+/// 
+/// [`propagate`]: ErrorCollector::propagate
+/// 
+/// # Deriving
+/// 
+/// With the `derive` feature enabled, this trait can be derived for a struct which stores its
+/// errors in a `Vec<E>` field. The field must be marked with `#[errors]`:
+/// 
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use ocm::{ErrorCollector, ErrorSentinel};
+/// 
+/// #[derive(ErrorCollector, Default)]
+/// struct Diagnostics {
+///     #[errors]
+///     errors: Vec<String>,
+///     file_name: String,
+/// }
+/// 
+/// let mut diagnostics = Diagnostics::default();
+/// diagnostics.push_error("unexpected token".to_string());
+/// assert_eq!(diagnostics.error_count(), 1);
+/// 
+/// let mut sentinel = ErrorSentinel::empty();
+/// let diagnostics = diagnostics.propagate(&mut sentinel);
+/// assert!(!diagnostics.has_errors());
+/// assert_eq!(sentinel.peek(), &["unexpected token".to_string()]);
+/// # sentinel.handle(|_| ());
+/// # }
+/// ```
 pub trait ErrorCollector<E> {
     /// The type returned by [`propagate`].
     /// 
     /// [`propagate`]: ErrorCollector::propagate
-    type WrappedInner;
+    type WrappedInner where Self: Sized;
 
     /// Add a new error to the collection of errors.
     fn push_error(&mut self, error: E);
 
+    /// The errors which have been collected, in the order they were pushed.
+    /// 
+    /// Returns `None` for collectors which don't store their errors as a contiguous slice of `E`,
+    /// such as sets, or adapters which wrap each error in another type. [`for_each_error`] works
+    /// with any collector.
+    /// 
+    /// [`for_each_error`]: ErrorCollector::for_each_error
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, Outcome};
+    /// fn first_error<E>(collector: &impl ErrorCollector<E>) -> Option<&E> {
+    ///     collector.errors()?.first()
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// assert_eq!(first_error(&o), Some(&"error 1"));
+    /// # o.finalize().1.ignore();
+    /// ```
+    fn errors(&self) -> Option<&[E]> {
+        None
+    }
+
+    /// Calls `f` with each error which has been collected, in the order they were pushed if the
+    /// collector keeps track of it.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, Outcome};
+    /// # use std::collections::BTreeSet;
+    /// fn describe(collector: &impl ErrorCollector<&'static str>) -> String {
+    ///     let mut description = String::new();
+    ///     collector.for_each_error(&mut |error| description.push_str(error));
+    ///     description
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors(42, vec!["b", "a"]);
+    /// assert_eq!(describe(&o), "ba");
+    /// # o.finalize().1.ignore();
+    /// 
+    /// let set = BTreeSet::from(["b", "a"]);
+    /// assert_eq!(describe(&set), "ab");
+    /// ```
+    fn for_each_error(&self, f: &mut dyn FnMut(&E));
+
+    /// The number of errors which have been collected.
+    /// 
+    /// Collectors which pass their errors on as soon as they are pushed, or discard them, can't
+    /// always keep count. Implementations like this document what they return instead - for
+    /// example, channel senders always return 0, so [`has_errors`](ErrorCollector::has_errors) is
+    /// `false` even after errors have been sent. Check the receiving end of the channel instead.
+    fn error_count(&self) -> usize {
+        match self.errors() {
+            Some(errors) => errors.len(),
+            None => {
+                let mut count = 0;
+                self.for_each_error(&mut |_| count += 1);
+                count
+            }
+        }
+    }
+
+    /// Returns `true` if any errors have been collected.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, ErrorSentinel};
+    /// fn summarise(collector: &impl ErrorCollector<String>) -> String {
+    ///     if collector.has_errors() {
+    ///         format!("failed with {} error(s)", collector.error_count())
+    ///     } else {
+    ///         "succeeded".to_owned()
+    ///     }
+    /// }
+    /// 
+    /// let mut errors = ErrorSentinel::empty();
+    /// assert_eq!(summarise(&errors), "succeeded");
+    /// 
+    /// errors.push_error("oh no".to_owned());
+    /// assert_eq!(summarise(&errors), "failed with 1 error(s)");
+    /// # errors.ignore();
+    /// ```
+    fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
     /// Consumes this collector and pushes all of its errors into a different collector. If the type
     /// is wrapping some kind of value, it may return it too.
-    fn propagate(self, other: &mut impl ErrorCollector<E>) -> Self::WrappedInner;
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner
+    where Self: Sized;
+
+    /// Moves all of the errors which this collector holds into a different collector, leaving this
+    /// one without any errors. Unlike [`propagate`], this doesn't consume the collector, which is
+    /// how a `&mut` reference to a collector is able to propagate its errors.
+    /// 
+    /// Collectors which don't hold on to their errors, such as channel senders, have nothing to
+    /// move.
+    /// 
+    /// [`propagate`]: ErrorCollector::propagate
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, ErrorSentinel};
+    /// let mut errors = vec!["error 1", "error 2"];
+    /// let mut sentinel = ErrorSentinel::empty();
+    /// errors.drain_into(&mut sentinel);
+    /// 
+    /// assert!(errors.is_empty());
+    /// assert_eq!(sentinel.peek(), &["error 1", "error 2"]);
+    /// # sentinel.ignore();
+    /// ```
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>);
+}
+
+/// A plain [`Vec`] can collect errors, so that existing code which already owns a list of errors
+/// can receive propagated errors directly.
+/// 
+/// The errors in a `Vec` are not protected by an [`ErrorSentinel`], so nothing checks that they
+/// are handled.
+/// 
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// let mut diagnostics = vec!["existing error"];
+/// 
+/// let value = Outcome::new_with_errors(42, vec!["error 1", "error 2"]).propagate(&mut diagnostics);
+/// assert_eq!(value, 42);
+/// assert_eq!(diagnostics, vec!["existing error", "error 1", "error 2"]);
+/// ```
+impl<E> ErrorCollector<E> for Vec<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.push(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        Some(self)
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in self.drain(..) {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A [`VecDeque`] can collect errors, pushing each onto the back. This is useful for keeping a
+/// bounded window of the most recent errors, by popping from the front.
+/// 
+/// [`errors`](ErrorCollector::errors) returns the errors while they are stored contiguously, and
+/// `None` once the deque has wrapped around its buffer - call [`VecDeque::make_contiguous`] to get
+/// a slice again. [`for_each_error`](ErrorCollector::for_each_error) always visits them from front
+/// to back, and propagating a `VecDeque` moves them in that order. Like a [`Vec`], the errors are
+/// not protected by an [`ErrorSentinel`](crate::ErrorSentinel).
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::VecDeque;
+/// let mut recent = VecDeque::new();
+/// for i in 0..5 {
+///     Outcome::new_with_errors((), vec![i]).propagate(&mut recent);
+///     if recent.len() > 3 {
+///         recent.pop_front();
+///     }
+/// }
+/// 
+/// assert_eq!(recent.error_count(), 3);
+/// assert_eq!(recent, [2, 3, 4]);
+/// 
+/// let mut visited = vec![];
+/// recent.for_each_error(&mut |error| visited.push(*error));
+/// assert_eq!(visited, vec![2, 3, 4]);
+/// 
+/// recent.make_contiguous();
+/// assert_eq!(recent.errors(), Some(&[2, 3, 4][..]));
+/// ```
+impl<E> ErrorCollector<E> for VecDeque<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.push_back(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        match self.as_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in self.drain(..) {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A [`BinaryHeap`] can collect errors, ordering them by priority so that the greatest error can be
+/// retrieved first.
+/// 
+/// Since the errors are not kept in the order they were pushed, [`errors`](ErrorCollector::errors)
+/// returns `None`, and [`for_each_error`](ErrorCollector::for_each_error) visits them in an
+/// arbitrary order. Propagating a `BinaryHeap` moves its errors from greatest to least. Like a
+/// [`Vec`], the errors are not protected by an [`ErrorSentinel`](crate::ErrorSentinel).
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::BinaryHeap;
+/// let mut by_priority = BinaryHeap::new();
+/// Outcome::new_with_errors((), vec![(1, "minor"), (3, "critical"), (2, "major")])
+///     .propagate(&mut by_priority);
+/// 
+/// let mut total = 0;
+/// by_priority.for_each_error(&mut |(priority, _)| total += priority);
+/// assert_eq!(total, 6);
+/// 
+/// assert_eq!(by_priority.pop(), Some((3, "critical")));
+/// 
+/// let mut rest = vec![];
+/// by_priority.propagate(&mut rest);
+/// assert_eq!(rest, vec![(2, "major"), (1, "minor")]);
+/// ```
+impl<E: Ord> ErrorCollector<E> for BinaryHeap<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.push(error);
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self.into_sorted_vec().into_iter().rev() {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in core::mem::take(self).into_sorted_vec().into_iter().rev() {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A [`LinkedList`] can collect errors, pushing each onto the back.
+/// 
+/// Since the errors are not stored contiguously, [`errors`](ErrorCollector::errors) returns `None`.
+/// [`for_each_error`](ErrorCollector::for_each_error) visits them from front to back, and
+/// propagating a `LinkedList` moves them in that order. Like a [`Vec`], the errors are not
+/// protected by an [`ErrorSentinel`](crate::ErrorSentinel).
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::LinkedList;
+/// let mut errors = LinkedList::new();
+/// Outcome::new_with_errors((), vec!["error 1", "error 2"]).propagate(&mut errors);
+/// errors.push_front("error 0");
+/// 
+/// assert_eq!(errors.error_count(), 3);
+/// assert_eq!(errors.errors(), None);
+/// 
+/// let mut visited = vec![];
+/// errors.for_each_error(&mut |error| visited.push(*error));
+/// assert_eq!(visited, vec!["error 0", "error 1", "error 2"]);
+/// assert_eq!(errors.into_iter().collect::<Vec<_>>(), vec!["error 0", "error 1", "error 2"]);
+/// ```
+impl<E> ErrorCollector<E> for LinkedList<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.push_back(error);
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        while let Some(error) = self.pop_front() {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A [`HashSet`] can collect errors, discarding any duplicates. This is useful when the same
+/// error can legitimately be produced by many code paths.
+/// 
+/// The order of the errors is lost, so [`errors`](ErrorCollector::errors) returns `None`, and
+/// [`for_each_error`](ErrorCollector::for_each_error) visits them in an arbitrary order. Like a
+/// [`Vec`], the errors are not protected by an [`ErrorSentinel`](crate::ErrorSentinel).
+/// 
+/// Requires the `std` feature. [`BTreeSet`] is also a collector, which keeps its errors sorted.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::HashSet;
+/// let mut errors = HashSet::new();
+/// Outcome::new_with_errors((), vec!["missing file", "bad input", "missing file"]).propagate(&mut errors);
+/// errors.push_error("bad input");
+/// 
+/// assert_eq!(errors.error_count(), 2);
+/// assert!(errors.contains("missing file"));
+/// 
+/// let mut total_length = 0;
+/// errors.for_each_error(&mut |error| total_length += error.len());
+/// assert_eq!(total_length, "missing file".len() + "bad input".len());
+/// ```
+#[cfg(feature = "std")]
+impl<E: Eq + Hash> ErrorCollector<E> for HashSet<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.insert(error);
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in self.drain() {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A [`BTreeSet`] can collect errors, discarding any duplicates and keeping them sorted.
+/// 
+/// Since the errors are not kept in the order they were pushed, [`errors`](ErrorCollector::errors)
+/// returns `None`, and [`for_each_error`](ErrorCollector::for_each_error) visits them in sorted
+/// order. Like a [`Vec`], the errors are not protected by an
+/// [`ErrorSentinel`](crate::ErrorSentinel).
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::BTreeSet;
+/// fn last_error<E: Clone>(collector: &impl ErrorCollector<E>) -> Option<E> {
+///     let mut last = None;
+///     collector.for_each_error(&mut |error| last = Some(error.clone()));
+///     last
+/// }
+/// 
+/// let mut errors = BTreeSet::new();
+/// Outcome::new_with_errors((), vec!["c", "a", "c", "b", "a"]).propagate(&mut errors);
+/// 
+/// assert_eq!(errors.error_count(), 3);
+/// assert_eq!(last_error(&errors), Some("c"));
+/// assert_eq!(errors.into_iter().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+/// ```
+impl<E: Ord> ErrorCollector<E> for BTreeSet<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.insert(error);
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        for error in self {
+            other.push_error(error);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        while let Some(error) = self.pop_first() {
+            other.push_error(error);
+        }
+    }
+}
+
+/// A mutable reference to a collector is also a collector, which pushes errors into the referenced
+/// collector. This means functions which take a collector by value, or are generic over
+/// `C: ErrorCollector<E>`, can be given a reference and pass it along to nested helpers without
+/// giving up ownership.
+/// 
+/// [`propagate`] moves the errors out of the referenced collector with [`drain_into`], so generic
+/// code which propagates the collector it was given behaves the same whether it was given an owned
+/// collector or a reference to one. The referenced collector is left without errors, but is still
+/// owned by its caller - so, for example, an empty [`ErrorSentinel`] still needs to be handled.
+/// 
+/// [`propagate`]: ErrorCollector::propagate
+/// [`drain_into`]: ErrorCollector::drain_into
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel};
+/// fn check_config(mut errors: impl ErrorCollector<String>, entries: &[(&str, &str)]) {
+///     for (key, value) in entries {
+///         check_entry(&mut errors, key, value);
+///     }
+/// }
+/// 
+/// fn check_entry<C: ErrorCollector<String>>(mut errors: C, key: &str, value: &str) {
+///     if key.is_empty() {
+///         errors.push_error("empty key".to_owned());
+///     }
+///     check_value(&mut errors, value);
+/// }
+/// 
+/// fn check_value(mut errors: impl ErrorCollector<String>, value: &str) {
+///     if value.is_empty() {
+///         errors.push_error("empty value".to_owned());
+///     }
+/// }
+/// 
+/// let mut errors = ErrorSentinel::empty();
+/// check_config(&mut errors, &[("a", "1"), ("", "2"), ("b", "")]);
+/// assert_eq!(errors.peek(), &["empty key", "empty value"]);
+/// # errors.ignore();
+/// ```
+/// 
+/// Propagating through a reference moves the errors into the destination:
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, Outcome};
+/// fn forward<E>(collector: impl ErrorCollector<E>, out: &mut Outcome<u32, E>) {
+///     collector.propagate(out);
+/// }
+/// 
+/// let mut errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+/// let mut out = Outcome::new(42);
+/// forward(&mut errors, &mut out);
+/// 
+/// assert!(!errors.has_errors());
+/// assert_eq!(out.len_errors(), 2);
+/// assert_eq!(out.iter_errors().collect::<Vec<_>>(), vec![&"error 1", &"error 2"]);
+/// # errors.ignore();
+/// # out.finalize().1.ignore();
+/// ```
+impl<E, C: ErrorCollector<E> + ?Sized> ErrorCollector<E> for &mut C {
+    type WrappedInner = ();
+
+    #[track_caller]
+    fn push_error(&mut self, error: E) {
+        (**self).push_error(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        (**self).errors()
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        (**self).for_each_error(f);
+    }
+
+    fn error_count(&self) -> usize {
+        (**self).error_count()
+    }
+
+    fn has_errors(&self) -> bool {
+        (**self).has_errors()
+    }
+
+    fn propagate(self, mut other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        (*self).drain_into(&mut other);
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        (**self).drain_into(other);
+    }
 }
@@ -0,0 +1,154 @@
+use alloc::{collections::{BTreeSet, VecDeque}, vec::Vec};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A collection which an [`Outcome`] can store its errors in.
+/// 
+/// By default, an `Outcome` stores its errors in a [`Vec`], but its third type parameter can
+/// instead select a [`VecDeque`] or a [`BTreeSet`]. A `BTreeSet` automatically discards duplicate
+/// errors and keeps them sorted.
+/// 
+/// Most methods on `Outcome` are only available with the default `Vec` container, including its
+/// constructors. Use [`Outcome::with_container`] to create an `Outcome` with a different
+/// container. Pushing, inspecting, propagating and finalizing errors work with any container.
+/// 
+/// This trait is sealed, so it can't be implemented outside of this crate.
+/// 
+/// [`Outcome`]: crate::Outcome
+/// [`Outcome::with_container`]: crate::Outcome::with_container
+/// 
+/// ```
+/// # use ocm::Outcome;
+/// # use std::collections::BTreeSet;
+/// let mut o: Outcome<u32, &str, BTreeSet<_>> = Outcome::with_container(42, BTreeSet::new());
+/// o.push_error("b");
+/// o.push_error("a");
+/// o.push_error("b");
+/// assert_eq!(o.len_errors(), 2);
+/// 
+/// let (value, errors) = o.finalize();
+/// assert_eq!(value, 42);
+/// assert_eq!(errors.peek(), &["a", "b"]);
+/// # errors.ignore();
+/// ```
+/// 
+/// An `Outcome` with any container is an [`ErrorCollector`], so it can be passed to generic code:
+/// 
+/// [`ErrorCollector`]: crate::ErrorCollector
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome};
+/// # use std::collections::BTreeSet;
+/// fn describe(collector: &impl ErrorCollector<&'static str>) -> String {
+///     let mut errors = vec![];
+///     collector.for_each_error(&mut |error| errors.push(*error));
+///     format!("{} error(s): {}", collector.error_count(), errors.join(", "))
+/// }
+/// 
+/// let mut o: Outcome<(), &str, BTreeSet<_>> = Outcome::with_container((), BTreeSet::new());
+/// o.push_error("b");
+/// o.push_error("a");
+/// assert!(o.has_errors());
+/// assert_eq!(describe(&o), "2 error(s): a, b");
+/// # o.finalize().1.ignore();
+/// ```
+pub trait ErrorContainer<E>: IntoIterator<Item = E> + Default + sealed::Sealed {
+    /// An iterator over references to the errors.
+    type Iter<'a>: Iterator<Item = &'a E> where Self: 'a, E: 'a;
+
+    /// Adds an error to the container.
+    fn push(&mut self, error: E);
+
+    /// Adds each error from an iterator to the container.
+    fn extend_errors(&mut self, errors: impl IntoIterator<Item = E>) {
+        for error in errors {
+            self.push(error);
+        }
+    }
+
+    /// The number of errors in the container.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the container has no errors.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over references to the errors in the container.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// The errors as a contiguous slice, or `None` if the container doesn't currently store them
+    /// that way. Use [`iter`](ErrorContainer::iter) to access the errors of any container.
+    fn as_slice(&self) -> Option<&[E]>;
+}
+
+impl<E> sealed::Sealed for Vec<E> {}
+
+impl<E> ErrorContainer<E> for Vec<E> {
+    type Iter<'a> = core::slice::Iter<'a, E> where E: 'a;
+
+    fn push(&mut self, error: E) {
+        Vec::push(self, error);
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self[..].iter()
+    }
+
+    fn as_slice(&self) -> Option<&[E]> {
+        Some(self)
+    }
+}
+
+impl<E> sealed::Sealed for VecDeque<E> {}
+
+impl<E> ErrorContainer<E> for VecDeque<E> {
+    type Iter<'a> = alloc::collections::vec_deque::Iter<'a, E> where E: 'a;
+
+    fn push(&mut self, error: E) {
+        self.push_back(error);
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        VecDeque::iter(self)
+    }
+
+    fn as_slice(&self) -> Option<&[E]> {
+        match self.as_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+}
+
+impl<E> sealed::Sealed for BTreeSet<E> {}
+
+impl<E: Ord> ErrorContainer<E> for BTreeSet<E> {
+    type Iter<'a> = alloc::collections::btree_set::Iter<'a, E> where E: 'a;
+
+    fn push(&mut self, error: E) {
+        self.insert(error);
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeSet::iter(self)
+    }
+
+    fn as_slice(&self) -> Option<&[E]> {
+        None
+    }
+}
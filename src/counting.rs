@@ -0,0 +1,74 @@
+use crate::ErrorCollector;
+
+/// An [`ErrorCollector`] which only counts how many errors are pushed into it, discarding the
+/// errors themselves.
+/// 
+/// This is an intentional information-discarding sink, for when only "did anything fail, and how
+/// many times" matters, and keeping the errors would waste memory. Unlike
+/// [`ErrorSentinel::ignore`], the decision to discard errors is made up front, when choosing the
+/// collector.
+/// 
+/// Since no errors are kept, [`for_each_error`](ErrorCollector::for_each_error) visits nothing, and
+/// propagating a `CountingCollector` pushes nothing, returning the count instead.
+/// 
+/// [`ErrorSentinel::ignore`]: crate::ErrorSentinel::ignore
+/// 
+/// ```
+/// # use ocm::{CountingCollector, Outcome};
+/// let o = Outcome::new_with_errors((), (0..10_000).map(|i| format!("error {i}")).collect());
+/// 
+/// let mut counter = CountingCollector::new();
+/// o.propagate(&mut counter);
+/// assert!(counter.has_errors());
+/// assert_eq!(counter.into_count(), 10_000);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct CountingCollector {
+    count: usize,
+}
+
+impl CountingCollector {
+    /// Creates a new `CountingCollector` with a count of zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    /// The number of errors which have been pushed.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if any errors have been pushed.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.count > 0
+    }
+
+    /// Consumes this `CountingCollector`, returning the number of errors which have been pushed.
+    #[must_use]
+    pub fn into_count(self) -> usize {
+        self.count
+    }
+}
+
+impl<E> ErrorCollector<E> for CountingCollector {
+    type WrappedInner = usize;
+
+    fn push_error(&mut self, _error: E) {
+        self.count += 1;
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn error_count(&self) -> usize {
+        self.count
+    }
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.count
+    }
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
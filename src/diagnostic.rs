@@ -0,0 +1,60 @@
+use core::{error::Error, fmt::Display};
+
+use alloc::{boxed::Box, vec::Vec};
+
+use miette::{Diagnostic, Severity};
+
+/// A collection of [`miette`] diagnostics which implements [`Diagnostic`] itself, so that
+/// accumulated errors can be rendered together in a report.
+/// 
+/// Each error is reported as a [`related`] diagnostic, in the order they were accumulated, and is
+/// rendered with its own severity. The severity of the `MultiDiagnostic` itself is the most severe
+/// of its errors, treating errors without a severity as [`Severity::Error`].
+/// 
+/// Create one from an [`Outcome`] with [`into_miette`], or report an [`ErrorSentinel`] directly
+/// with [`into_miette_report`].
+/// 
+/// Requires the `miette` feature.
+/// 
+/// [`related`]: Diagnostic::related
+/// [`Outcome`]: crate::Outcome
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// [`into_miette`]: crate::Outcome::into_miette
+/// [`into_miette_report`]: crate::ErrorSentinel::into_miette_report
+/// 
+/// ```
+/// # use ocm::MultiDiagnostic;
+/// use miette::{Diagnostic, MietteDiagnostic, Severity};
+/// 
+/// let error = MultiDiagnostic(vec![
+///     MietteDiagnostic::new("unused variable").with_severity(Severity::Warning),
+///     MietteDiagnostic::new("missing semicolon"),
+/// ]);
+/// 
+/// let related: Vec<_> = error.related().unwrap().map(|d| d.to_string()).collect();
+/// assert_eq!(related, vec!["unused variable", "missing semicolon"]);
+/// assert_eq!(error.severity(), Some(Severity::Error));
+/// assert_eq!(error.to_string(), "2 error(s)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MultiDiagnostic<E>(pub Vec<E>);
+
+impl<E> Display for MultiDiagnostic<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} error(s)", self.0.len())
+    }
+}
+
+impl<E: Diagnostic> Error for MultiDiagnostic<E> {}
+
+impl<E: Diagnostic> Diagnostic for MultiDiagnostic<E> {
+    fn severity(&self) -> Option<Severity> {
+        self.0.iter()
+            .map(|error| error.severity().unwrap_or(Severity::Error))
+            .max()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        Some(Box::new(self.0.iter().map(|error| error as &dyn Diagnostic)))
+    }
+}
@@ -0,0 +1,294 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{ErrorCollector, Outcome};
+
+/// Extension methods for [`Result`], to conveniently move errors into an [`ErrorCollector`].
+/// 
+/// These are mostly useful inside [`Outcome::build`], where a fallible operation should add its
+/// error to the collection and continue, rather than returning early.
+/// 
+/// ```
+/// # use ocm::{Outcome, ResultExt};
+/// let o = Outcome::build(|errs| {
+///     let a: u32 = "12".parse().or_push_default(errs);
+///     let b: u32 = "abc".parse().or_push_default(errs);
+///     let c: u32 = "30".parse().or_push_default(errs);
+///     a + b + c
+/// });
+/// 
+/// let (value, errors) = o.finalize();
+/// assert_eq!(value, 12 + 30);
+/// assert_eq!(errors.len(), 1);
+/// # errors.ignore();
+/// ```
+pub trait ResultExt<T, E> {
+    /// If this is an `Err`, pushes the error into a collector and returns `None`. Otherwise,
+    /// returns `Some` with the value.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ResultExt};
+    /// let o = Outcome::build(|errs| {
+    ///     let nums: Vec<u32> = ["1", "a", "3"].into_iter()
+    ///         .filter_map(|s| s.parse().or_push(errs))
+    ///         .collect();
+    ///     nums
+    /// });
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    fn or_push(self, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> Option<T>;
+
+    /// If this is an `Err`, pushes the error into a collector and returns the [`Default`] value of
+    /// `T`. Otherwise, returns the value.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorSentinel, ResultExt};
+    /// let mut errs = ErrorSentinel::empty();
+    /// assert_eq!("12".parse::<u32>().or_push_default(&mut errs), 12);
+    /// assert_eq!("abc".parse::<u32>().or_push_default(&mut errs), 0);
+    /// 
+    /// assert_eq!(errs.len(), 1);
+    /// # errs.ignore();
+    /// ```
+    fn or_push_default(self, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> T
+    where T : Default;
+
+    /// Converts this `Result` into an [`Outcome`]. See [`Outcome::from_result`].
+    /// 
+    /// ```
+    /// # use ocm::ResultExt;
+    /// let o = "12".parse::<u32>().into_outcome();
+    /// assert_eq!(o.unwrap(), 12);
+    /// 
+    /// let o = "abc".parse::<u32>().into_outcome();
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 0);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    fn into_outcome(self) -> Outcome<T, E>
+    where T : Default;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[track_caller]
+    fn or_push(self, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(error) => {
+                collector.push_error(error);
+                None
+            }
+        }
+    }
+
+    #[track_caller]
+    fn or_push_default(self, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> T
+    where T : Default
+    {
+        self.or_push(collector).unwrap_or_default()
+    }
+
+    fn into_outcome(self) -> Outcome<T, E>
+    where T : Default
+    {
+        Outcome::from_result(self)
+    }
+}
+
+/// Extension methods for [`Option`], to conveniently record an error in an [`ErrorCollector`] when
+/// a value is missing.
+/// 
+/// ```
+/// # use ocm::{Outcome, OptionExt};
+/// # use std::collections::HashMap;
+/// let ages = HashMap::from([("alice", 30), ("bob", 25)]);
+/// 
+/// let o = Outcome::build(|errs| {
+///     ["alice", "carol", "bob"].into_iter()
+///         .map(|name| ages.get(name).copied()
+///             .unwrap_or_push_default(|| format!("unknown person: {name}"), errs))
+///         .sum::<u32>()
+/// });
+/// 
+/// let (value, errors) = o.finalize();
+/// assert_eq!(value, 30 + 25);
+/// assert_eq!(errors.peek(), &["unknown person: carol"]);
+/// # errors.ignore();
+/// ```
+pub trait OptionExt<T> {
+    /// If this is `None`, pushes an error into a collector. The error is only constructed if it is
+    /// needed. Returns this `Option` unchanged.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorSentinel, OptionExt};
+    /// let mut errs = ErrorSentinel::empty();
+    /// assert_eq!(Some(1).ok_or_push(|| "missing", &mut errs), Some(1));
+    /// assert_eq!(None::<u32>.ok_or_push(|| "missing", &mut errs), None);
+    /// 
+    /// assert_eq!(errs.peek(), &["missing"]);
+    /// # errs.ignore();
+    /// ```
+    fn ok_or_push<E>(self, error: impl FnOnce() -> E, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> Option<T>;
+
+    /// If this is `None`, pushes an error into a collector and returns the [`Default`] value of
+    /// `T`. Otherwise, returns the value. The error is only constructed if it is needed.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorSentinel, OptionExt};
+    /// let mut errs = ErrorSentinel::empty();
+    /// assert_eq!(Some(1).unwrap_or_push_default(|| "missing", &mut errs), 1);
+    /// assert_eq!(None::<u32>.unwrap_or_push_default(|| "missing", &mut errs), 0);
+    /// 
+    /// assert_eq!(errs.peek(), &["missing"]);
+    /// # errs.ignore();
+    /// ```
+    fn unwrap_or_push_default<E>(self, error: impl FnOnce() -> E, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> T
+    where T : Default;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
+    fn ok_or_push<E>(self, error: impl FnOnce() -> E, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> Option<T> {
+        if self.is_none() {
+            collector.push_error(error());
+        }
+        self
+    }
+
+    #[track_caller]
+    fn unwrap_or_push_default<E>(self, error: impl FnOnce() -> E, collector: &mut (impl ErrorCollector<E> + ?Sized)) -> T
+    where T : Default
+    {
+        self.ok_or_push(error, collector).unwrap_or_default()
+    }
+}
+
+/// Extension methods for [`Iterator`], to combine items which may produce errors.
+pub trait IteratorExt: Iterator + Sized {
+    /// Collects an iterator of [`Result`]s into an [`Outcome`]. See [`Outcome::from_results`].
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, IteratorExt};
+    /// let o: Outcome<Vec<_>, _> = ["1", "a", "2", "b", "3"].into_iter()
+    ///     .map(|s| s.parse::<u32>())
+    ///     .collect_outcome();
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(errors.len(), 2);
+    /// # errors.ignore();
+    /// ```
+    fn collect_outcome<C, T, E>(self) -> Outcome<C, E>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        Outcome::from_results(self)
+    }
+
+    /// Lazily applies a function which produces an [`Outcome`] to each item, yielding the values
+    /// and moving the errors into a collector.
+    /// 
+    /// Errors are pushed as each item is processed, so they are in iteration order. If the iterator
+    /// is not driven to completion, only the errors from items which were actually processed are
+    /// pushed.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorSentinel, IteratorExt, ErrorCollector};
+    /// fn parse(s: &str) -> Outcome<u32, String> {
+    ///     Outcome::build(|errs| {
+    ///         s.parse().unwrap_or_else(|_| {
+    ///             errs.push_error(format!("not a number: {s}"));
+    ///             0
+    ///         })
+    ///     })
+    /// }
+    /// 
+    /// let mut errs = ErrorSentinel::empty();
+    /// let first_big = ["1", "a", "200", "b", "300"].into_iter()
+    ///     .map_collecting(parse, &mut errs)
+    ///     .find(|n| *n > 100);
+    /// 
+    /// assert_eq!(first_big, Some(200));
+    /// // "b" was never processed, so there is no error for it
+    /// assert_eq!(errs.peek(), &["not a number: a"]);
+    /// # errs.ignore();
+    /// ```
+    fn map_collecting<U, E, F, C>(self, func: F, collector: &mut C) -> MapCollecting<'_, Self, F, C>
+    where
+        F: FnMut(Self::Item) -> Outcome<U, E>,
+        C: ErrorCollector<E> + ?Sized,
+    {
+        MapCollecting { iter: self, func, collector }
+    }
+
+    /// Separates an iterator of [`Outcome`]s into the values of those which succeeded, and the
+    /// `Outcome`s which have errors.
+    /// 
+    /// Unlike collecting into a single `Outcome`, this keeps track of which items failed. The
+    /// failed items are kept as `Outcome`s, so their values are still available, and their errors
+    /// must still be handled. Both collections preserve iteration order.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, IteratorExt};
+    /// let outcomes = vec![
+    ///     Outcome::new(1),
+    ///     Outcome::new_with_errors(2, vec!["error 1"]),
+    ///     Outcome::new(3),
+    ///     Outcome::new_with_errors(4, vec!["error 2", "error 3"]),
+    /// ];
+    /// 
+    /// let (clean, failed) = outcomes.into_iter().partition_outcomes();
+    /// assert_eq!(clean, vec![1, 3]);
+    /// 
+    /// let failed: Vec<_> = failed.into_iter().map(Outcome::into_parts).collect();
+    /// assert_eq!(failed, vec![(2, vec!["error 1"]), (4, vec!["error 2", "error 3"])]);
+    /// ```
+    fn partition_outcomes<T, E>(self) -> (Vec<T>, Vec<Outcome<T, E>>)
+    where
+        Self: Iterator<Item = Outcome<T, E>>,
+    {
+        let mut clean = vec![];
+        let mut failed = vec![];
+        for outcome in self {
+            if outcome.is_success() {
+                clean.push(outcome.into_parts().0);
+            } else {
+                failed.push(outcome);
+            }
+        }
+        (clean, failed)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// An iterator which maps items to [`Outcome`]s, yielding their values and moving their errors
+/// into a collector. Created with [`IteratorExt::map_collecting`].
+pub struct MapCollecting<'c, I, F, C: ?Sized> {
+    iter: I,
+    func: F,
+    collector: &'c mut C,
+}
+
+impl<'c, I, F, C, U, E> Iterator for MapCollecting<'c, I, F, C>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Outcome<U, E>,
+    C: ErrorCollector<E> + ?Sized,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some((self.func)(item).propagate(self.collector))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt::Display;
+
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// A function which is called instead of panicking when errors are dropped without being handled.
+/// Installed with [`set_unhandled_hook`].
+#[cfg(feature = "std")]
+pub type UnhandledHook = fn(&UnhandledErrors);
+
+#[cfg(feature = "std")]
+static UNHANDLED_HOOK: RwLock<Option<UnhandledHook>> = RwLock::new(None);
+
+/// Describes an [`ErrorSentinel`] or [`ErrorSentinelIter`] which was dropped without handling its
+/// errors.
+#[cfg_attr(feature = "std", doc = "Passed to the hook installed by [`set_unhandled_hook`].")]
+/// 
+/// The [`Display`] implementation gives the same message which would be used for the panic if no
+/// hook were installed.
+/// 
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// [`ErrorSentinelIter`]: crate::ErrorSentinelIter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnhandledErrors {
+    unhandled: usize,
+    total: usize,
+    from_iter: bool,
+    label: Option<&'static str>,
+}
+
+impl UnhandledErrors {
+    pub(crate) fn from_sentinel(total: usize, label: Option<&'static str>) -> Self {
+        Self { unhandled: total, total, from_iter: false, label }
+    }
+
+    pub(crate) fn from_iter(unhandled: usize, total: usize, label: Option<&'static str>) -> Self {
+        Self { unhandled, total, from_iter: true, label }
+    }
+
+    /// The number of errors which were not handled.
+    pub fn unhandled(&self) -> usize {
+        self.unhandled
+    }
+
+    /// The total number of errors which needed handling, including any which were handled.
+    /// 
+    /// This only differs from [`unhandled`] when an [`ErrorSentinelIter`] was partially consumed.
+    /// 
+    /// [`unhandled`]: UnhandledErrors::unhandled
+    /// [`ErrorSentinelIter`]: crate::ErrorSentinelIter
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The label given to the sentinel with [`ErrorSentinel::label`], if any.
+    /// 
+    /// [`ErrorSentinel::label`]: crate::ErrorSentinel::label
+    /// 
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// # use ocm::ErrorSentinel;
+    /// ocm::set_unhandled_hook(|info| {
+    ///     assert_eq!(info.label(), Some("parser"));
+    ///     assert_eq!(
+    ///         info.to_string(),
+    ///         "sentinel 'parser' iterator dropped without handling all errors: 1 out of 2 error(s) unhandled",
+    ///     );
+    /// });
+    /// 
+    /// let mut iter = ErrorSentinel::new(vec!["error 1", "error 2"])
+    ///     .label("parser")
+    ///     .into_errors_iter();
+    /// iter.next();
+    /// drop(iter);
+    /// # }
+    /// ```
+    pub fn label(&self) -> Option<&'static str> {
+        self.label
+    }
+}
+
+impl Display for UnhandledErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "sentinel ")?;
+        if let Some(label) = self.label {
+            write!(f, "'{label}' ")?;
+        }
+
+        if self.from_iter {
+            write!(
+                f,
+                "iterator dropped without handling all errors: {} out of {} error(s) unhandled",
+                self.unhandled,
+                self.total,
+            )
+        } else {
+            write!(f, "dropped without handling errors")
+        }
+    }
+}
+
+/// Installs a hook which is called when an [`ErrorSentinel`] or [`ErrorSentinelIter`] is dropped
+/// without handling its errors, replacing the default behaviour of panicking.
+/// 
+/// The panic is the right choice during development, but some production code paths may prefer to
+/// report the logic error somewhere and continue. The hook applies to every thread, and replaces
+/// any hook which was previously installed.
+/// 
+/// The errors themselves are not passed to the hook, since sentinels with any error type may be
+/// dropped. Instead, the hook receives an [`UnhandledErrors`] describing what went wrong.
+/// 
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// [`ErrorSentinelIter`]: crate::ErrorSentinelIter
+/// 
+/// ```
+/// # use ocm::{ErrorSentinel, UnhandledErrors};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// 
+/// static DROPPED_ERRORS: AtomicUsize = AtomicUsize::new(0);
+/// 
+/// ocm::set_unhandled_hook(|info: &UnhandledErrors| {
+///     eprintln!("warning: {info}");
+///     DROPPED_ERRORS.fetch_add(info.unhandled(), Ordering::SeqCst);
+/// });
+/// 
+/// {
+///     let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+///     // Would usually panic here, but calls the hook instead
+/// }
+/// 
+/// assert_eq!(DROPPED_ERRORS.load(Ordering::SeqCst), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_unhandled_hook(hook: UnhandledHook) {
+    *UNHANDLED_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Removes the hook installed by [`set_unhandled_hook`], if any, and returns it. Dropping
+/// unhandled errors will panic again afterwards.
+/// 
+/// ```should_panic
+/// # use ocm::ErrorSentinel;
+/// ocm::set_unhandled_hook(|_| ());
+/// ocm::take_unhandled_hook();
+/// 
+/// {
+///     let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+///     // Panic occurs here!
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn take_unhandled_hook() -> Option<UnhandledHook> {
+    UNHANDLED_HOOK.write().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// Calls the installed hook with the given details, or panics if there isn't one.
+#[cfg(feature = "std")]
+pub(crate) fn report_unhandled(info: UnhandledErrors) {
+    let hook = *UNHANDLED_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(&info),
+        None => panic!("{info}"),
+    }
+}
+
+/// Hooks are not supported without `std`, so always panics.
+#[cfg(not(feature = "std"))]
+pub(crate) fn report_unhandled(info: UnhandledErrors) {
+    panic!("{info}")
+}
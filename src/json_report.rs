@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+/// The envelope object of a JSON error report, produced by
+/// [`ErrorSentinel::to_json_report`](crate::ErrorSentinel::to_json_report).
+#[derive(Serialize)]
+pub(crate) struct JsonReport<'a, E> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<&'a str>,
+    pub count: usize,
+    pub errors: &'a [E],
+}
+
+impl<'a, E> JsonReport<'a, E> {
+    pub fn new(tool: Option<&'a str>, errors: &'a [E]) -> Self {
+        Self { tool, count: errors.len(), errors }
+    }
+}
@@ -0,0 +1,225 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::{ErrorSentinel, Outcome, Severity};
+
+/// How serious a [`Leveled`] error is.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// A problem which should be reported, but doesn't prevent the operation from succeeding.
+    Warning,
+
+    /// A problem which causes the operation to fail.
+    Error,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Level::Warning => write!(f, "warning"),
+            Level::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// An error paired with a [`Level`], so that warnings and errors can be accumulated in the same
+/// [`Outcome`].
+/// 
+/// `Leveled` implements [`Severity`], so the severity-aware methods on [`Outcome`] are available,
+/// such as [`is_fatal`] and [`warnings`]. Use [`Outcome::finalize_leveled`] to separate the
+/// warnings from the errors at the end.
+/// 
+/// [`is_fatal`]: Outcome::is_fatal
+/// [`warnings`]: Outcome::warnings
+/// 
+/// ```
+/// # use ocm::{Leveled, Outcome};
+/// let mut o = Outcome::new(());
+/// o.push_warning("unused variable `x`");
+/// assert!(o.is_success_ignoring_warnings());
+/// 
+/// o.push_fatal_error("missing semicolon");
+/// assert!(!o.is_success_ignoring_warnings());
+/// 
+/// let ((), errors) = o.finalize();
+/// assert_eq!(errors.peek()[0], Leveled::warning("unused variable `x`"));
+/// assert_eq!(errors.peek()[1].to_string(), "error: missing semicolon");
+/// # errors.ignore();
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Leveled<E> {
+    /// How serious the error is.
+    pub level: Level,
+
+    /// The error.
+    pub error: E,
+}
+
+impl<E> Leveled<E> {
+    /// Wraps an error with the given level.
+    #[must_use]
+    pub fn new(level: Level, error: E) -> Self {
+        Self { level, error }
+    }
+
+    /// Wraps an error as a [`Level::Warning`].
+    #[must_use]
+    pub fn warning(error: E) -> Self {
+        Self::new(Level::Warning, error)
+    }
+
+    /// Wraps an error as a [`Level::Error`].
+    #[must_use]
+    pub fn error(error: E) -> Self {
+        Self::new(Level::Error, error)
+    }
+
+    /// Discards the level, returning the error.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for Leveled<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.level, self.error)
+    }
+}
+
+impl<E> Severity for Leveled<E> {
+    fn is_fatal(&self) -> bool {
+        self.level == Level::Error
+    }
+}
+
+impl<T, E> Outcome<T, Leveled<E>> {
+    /// Pushes a [`Level::Warning`]. See the [`Leveled`] docs for an example.
+    pub fn push_warning(&mut self, error: E) {
+        self.push_error(Leveled::warning(error));
+    }
+
+    /// Pushes a [`Level::Error`]. See the [`Leveled`] docs for an example.
+    pub fn push_fatal_error(&mut self, error: E) {
+        self.push_error(Leveled::error(error));
+    }
+
+    /// Escalates every warning to an error, like `-D warnings` in `rustc`.
+    /// 
+    /// Afterwards, [`is_success_ignoring_warnings`] only holds if there were no warnings either,
+    /// and [`finalize_leveled`] returns every item as an error.
+    /// 
+    /// [`is_success_ignoring_warnings`]: Outcome::is_success_ignoring_warnings
+    /// [`finalize_leveled`]: Outcome::finalize_leveled
+    /// 
+    /// ```
+    /// # use ocm::{Leveled, Outcome};
+    /// let o = Outcome::new_with_errors((), vec![Leveled::warning("unused import")]);
+    /// assert!(o.is_success_ignoring_warnings());
+    /// 
+    /// let o = o.deny_warnings();
+    /// assert!(!o.is_success_ignoring_warnings());
+    /// 
+    /// let ((), errors, warnings) = o.finalize_leveled();
+    /// assert_eq!(errors.peek(), &["unused import"]);
+    /// assert!(warnings.is_empty());
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn deny_warnings(self) -> Self {
+        self.warn_is_error_if(|_| true)
+    }
+
+    /// Escalates the warnings which match a predicate to errors, leaving other warnings alone.
+    /// 
+    /// ```
+    /// # use ocm::{Leveled, Outcome};
+    /// let o = Outcome::new_with_errors((), vec![
+    ///     Leveled::warning("deprecated: `foo`"),
+    ///     Leveled::warning("unused variable `x`"),
+    ///     Leveled::error("type mismatch"),
+    ///     Leveled::warning("deprecated: `bar`"),
+    /// ]);
+    /// 
+    /// let o = o.warn_is_error_if(|w| w.starts_with("deprecated"));
+    /// assert_eq!(o.fatal_errors().count(), 3);
+    /// 
+    /// let ((), errors, warnings) = o.finalize_leveled();
+    /// assert_eq!(errors.peek(), &["deprecated: `foo`", "type mismatch", "deprecated: `bar`"]);
+    /// assert_eq!(warnings, vec!["unused variable `x`"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn warn_is_error_if(mut self, mut pred: impl FnMut(&E) -> bool) -> Self {
+        self.map_errors_in_place(|leveled| {
+            if leveled.level == Level::Warning && pred(&leveled.error) {
+                leveled.level = Level::Error;
+            }
+        });
+        self
+    }
+
+    /// Consumes this `Outcome` and returns its value, the errors, and the warnings, each in the
+    /// order they were pushed.
+    /// 
+    /// Only the errors are returned in an [`ErrorSentinel`] which must be handled. Warnings are
+    /// returned as a plain [`Vec`], so they can be reported or discarded as appropriate.
+    /// 
+    /// ```
+    /// # use ocm::{Leveled, Outcome};
+    /// let o = Outcome::new_with_errors(42, vec![
+    ///     Leveled::warning("unused import"),
+    ///     Leveled::error("type mismatch"),
+    ///     Leveled::warning("unused variable"),
+    /// ]);
+    /// 
+    /// let (value, errors, warnings) = o.finalize_leveled();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors.peek(), &["type mismatch"]);
+    /// assert_eq!(warnings, vec!["unused import", "unused variable"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn finalize_leveled(self) -> (T, ErrorSentinel<E>, Vec<E>) {
+        let (value, all) = self.into_parts();
+
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        for Leveled { level, error } in all {
+            match level {
+                Level::Warning => warnings.push(error),
+                Level::Error => errors.push(error),
+            }
+        }
+
+        (value, ErrorSentinel::new(errors), warnings)
+    }
+}
+
+impl<E> ErrorSentinel<Leveled<E>> {
+    /// Handles the errors by splitting them into two new sentinels, the first with the errors and
+    /// the second with the warnings. See [`ErrorSentinel::split`] for details.
+    /// 
+    /// This is useful when warnings should be reported differently from errors, but still need to
+    /// be handled somehow.
+    /// 
+    /// ```
+    /// # use ocm::{Leveled, Outcome};
+    /// let o = Outcome::new_with_errors((), vec![
+    ///     Leveled::warning("unused import"),
+    ///     Leveled::error("type mismatch"),
+    /// ]);
+    /// 
+    /// let ((), all) = o.finalize();
+    /// let (errors, warnings) = all.split_by_severity();
+    /// 
+    /// warnings.handle(|warnings| assert_eq!(warnings, vec!["unused import"]));
+    /// assert_eq!(errors.peek(), &["type mismatch"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use = "discarding the `ErrorSentinel`s will panic immediately"]
+    pub fn split_by_severity(self) -> (ErrorSentinel<E>, ErrorSentinel<E>) {
+        let (errors, warnings) = self.split(Severity::is_fatal);
+        (errors.map_errors(Leveled::into_inner), warnings.map_errors(Leveled::into_inner))
+    }
+}
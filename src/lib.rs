@@ -1,12 +1,128 @@
-#![feature(never_type)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[doc = include_str!("../README.md")]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2, try_trait_v2_residual))]
+
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
 
 mod outcome;
 pub use outcome::*;
 
+mod container;
+pub use container::*;
+
 mod sentinel;
 pub use sentinel::*;
 
 mod collector;
 pub use collector::*;
+
+mod hook;
+pub use hook::*;
+
+mod builder;
+pub use builder::*;
+
+mod success;
+pub use success::*;
+
+#[cfg(feature = "try_trait")]
+mod try_trait;
+
+mod ext;
+pub use ext::*;
+
+mod multi_error;
+pub use multi_error::*;
+
+mod string_error;
+pub use string_error::*;
+
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::*;
+
+#[cfg(feature = "miette")]
+mod diagnostic;
+#[cfg(feature = "miette")]
+pub use diagnostic::*;
+
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "tracing")]
+pub use trace::*;
+
+mod write;
+pub use write::*;
+
+#[cfg(feature = "std")]
+mod report;
+
+#[cfg(feature = "json-report")]
+mod json_report;
+
+mod bounded;
+pub use bounded::*;
+
+mod counting;
+pub use counting::*;
+
+mod sink;
+pub use sink::*;
+
+mod capped;
+pub use capped::*;
+
+mod tee;
+pub use tee::*;
+
+mod severity;
+pub use severity::*;
+
+mod leveled;
+pub use leveled::*;
+
+mod code;
+pub use code::*;
+
+mod stats;
+pub use stats::*;
+
+mod unordered;
+pub use unordered::*;
+
+#[cfg(feature = "std")]
+mod channel;
+
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use shared::*;
+
+#[cfg(feature = "tokio")]
+mod tokio_channel;
+#[cfg(feature = "tokio")]
+pub use tokio_channel::*;
+
+mod located;
+pub use located::*;
+
+mod spanned;
+pub use spanned::*;
+
+mod macros;
+
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "proptest")]
+pub use strategy::*;
+
+#[cfg(feature = "backtrace")]
+mod traced;
+#[cfg(feature = "backtrace")]
+pub use traced::*;
+
+#[cfg(feature = "derive")]
+pub use ocm_derive::ErrorCollector;
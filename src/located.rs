@@ -0,0 +1,198 @@
+use core::{fmt::Display, panic::Location};
+
+use crate::{ErrorCollector, ErrorSentinel, Outcome};
+
+/// An error paired with the source location where it was created, for tracking down which line of
+/// code produced a confusing diagnostic.
+/// 
+/// The location is captured with `#[track_caller]`, so it points at the code which called
+/// [`Located::new`] or [`ErrorSentinel::push_located`], rather than inside this crate.
+/// 
+/// ```
+/// # use ocm::{ErrorSentinel, Located};
+/// let mut errors = ErrorSentinel::empty();
+/// errors.push_located("something went wrong"); let line = line!();
+/// 
+/// let Located { error, location } = &errors.peek()[0];
+/// assert_eq!(*error, "something went wrong");
+/// assert_eq!(location.file(), file!());
+/// assert_eq!(location.line(), line);
+/// # errors.ignore();
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Located<E> {
+    /// The error.
+    pub error: E,
+
+    /// The location where the error was created.
+    pub location: &'static Location<'static>,
+}
+
+impl<E> Located<E> {
+    /// Wraps an error, capturing the location of the caller.
+    #[must_use]
+    #[track_caller]
+    pub fn new(error: E) -> Self {
+        Self { error, location: Location::caller() }
+    }
+
+    /// Discards the location, returning the error.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for Located<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} (at {})", self.error, self.location)
+    }
+}
+
+impl<E> ErrorSentinel<Located<E>> {
+    /// Pushes an error, along with the location of the caller.
+    /// 
+    /// The locations can be inspected through [`peek`](ErrorSentinel::peek) or when handling the
+    /// errors, since each error is a [`Located`]. See the `Located` docs for an example.
+    #[track_caller]
+    pub fn push_located(&mut self, error: E) {
+        self.push_error(Located::new(error));
+    }
+}
+
+impl<T, E> Outcome<T, Located<E>> {
+    /// Pushes an error, along with the location of the caller.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new(42);
+    /// o.push_error_located("oh no"); let line = line!();
+    /// 
+    /// let (_, errors) = o.finalize();
+    /// let located = &errors.peek()[0];
+    /// assert_eq!(located.location.line(), line);
+    /// assert_eq!(located.to_string(), format!("oh no (at {})", located.location));
+    /// # errors.ignore();
+    /// ```
+    #[track_caller]
+    pub fn push_error_located(&mut self, error: E) {
+        self.push_error(Located::new(error));
+    }
+}
+
+/// An [`ErrorCollector`] which wraps another collector of [`Located`] errors, so that plain errors
+/// pushed into it have the location of the `push_error` call recorded automatically.
+/// 
+/// This is a lighter-weight alternative to capturing a backtrace for each error. Code which
+/// accepts any `ErrorCollector<E>` can be given a `LocatedCollector` without any changes, although
+/// the recorded location is that of the `push_error` call, even when it is inside a helper.
+/// Errors pushed with [`ResultExt`] or [`OptionExt`] methods record the location of that call.
+/// 
+/// The location is found with `#[track_caller]`, which only follows direct calls. Errors which are
+/// pushed by this crate rather than your code get a location inside this crate instead. This
+/// includes errors propagated into a `LocatedCollector`, such as with [`Outcome::propagate`], and
+/// errors pushed through a `dyn ErrorCollector`, such as by [`Outcome::map_with_errors`].
+/// 
+/// [`ResultExt`]: crate::ResultExt
+/// [`OptionExt`]: crate::OptionExt
+/// [`Outcome::propagate`]: crate::Outcome::propagate
+/// [`Outcome::map_with_errors`]: crate::Outcome::map_with_errors
+/// 
+/// Since the inner collector stores [`Located`] errors, [`errors`](ErrorCollector::errors) returns
+/// `None`, but [`for_each_error`](ErrorCollector::for_each_error) visits each error without its
+/// location. Use [`errors_with_locations`] to access the locations too. Propagating a
+/// `LocatedCollector` into a collector of plain errors discards the locations.
+/// 
+/// [`errors_with_locations`]: LocatedCollector::errors_with_locations
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, LocatedCollector, OptionExt, Outcome, ResultExt};
+/// let mut collector = LocatedCollector::new(ErrorSentinel::empty());
+/// let first_line = line!() + 1;
+/// collector.push_error("error 1");
+/// Err::<(), _>("error 2").or_push(&mut collector);
+/// None::<u32>.unwrap_or_push_default(|| "error 3", &mut collector);
+/// 
+/// // Pushed by ocm, so recorded inside ocm rather than here
+/// Outcome::new_with_errors((), vec!["error 4"]).propagate(&mut collector);
+/// 
+/// let errors = collector.errors_with_locations().unwrap();
+/// let lines = errors[..3]
+///     .iter()
+///     .map(|located| located.location.line() - first_line)
+///     .collect::<Vec<_>>();
+/// assert_eq!(lines, vec![0, 1, 2]);
+/// assert_ne!(errors[3].location.file(), file!());
+/// 
+/// let mut errors = vec![];
+/// collector.for_each_error(&mut |error: &&str| errors.push(*error));
+/// assert_eq!(errors, vec!["error 1", "error 2", "error 3", "error 4"]);
+/// # collector.into_inner().ignore();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocatedCollector<C> {
+    inner: C,
+}
+
+impl<C> LocatedCollector<C> {
+    /// Wraps a collector, so that errors pushed into it have their location recorded.
+    #[must_use]
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// The errors which have been collected, along with their locations. Like
+    /// [`ErrorCollector::errors`], this returns `None` if the inner collector doesn't store its
+    /// errors as a contiguous slice.
+    #[must_use]
+    pub fn errors_with_locations<E>(&self) -> Option<&[Located<E>]>
+    where
+        C: ErrorCollector<Located<E>>,
+    {
+        self.inner.errors()
+    }
+
+    /// Consumes this `LocatedCollector`, returning the inner collector.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<E, C: ErrorCollector<Located<E>>> ErrorCollector<E> for LocatedCollector<C> {
+    type WrappedInner = C::WrappedInner;
+
+    #[track_caller]
+    fn push_error(&mut self, error: E) {
+        self.inner.push_error(Located::new(error));
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.inner.for_each_error(&mut |located| f(&located.error));
+    }
+
+    fn error_count(&self) -> usize {
+        self.inner.error_count()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        let mut located = ErrorSentinel::empty();
+        let wrapped = self.inner.propagate(&mut located);
+        located.handle(|errors| {
+            for error in errors {
+                other.push_error(error.into_inner());
+            }
+        });
+        wrapped
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        let mut located = ErrorSentinel::empty();
+        self.inner.drain_into(&mut located);
+        located.handle(|errors| {
+            for error in errors {
+                other.push_error(error.into_inner());
+            }
+        });
+    }
+}
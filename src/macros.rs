@@ -0,0 +1,133 @@
+/// Pushes an error into a collector if a condition is `false`, similar to `anyhow::ensure!`.
+/// 
+/// Unlike `anyhow::ensure!`, this does not return early, so that later checks can still find
+/// more errors. The error expression is only evaluated if the condition fails.
+/// 
+/// The collector can be anything which implements [`ErrorCollector`](crate::ErrorCollector),
+/// including a `&mut` reference to one.
+/// 
+/// ```
+/// # use ocm::Outcome;
+/// let o = Outcome::build_default(|errs| {
+///     let (width, height) = (0, -3);
+///     ocm::ensure_multi!(errs, width > 0, "width must be positive".to_owned());
+///     ocm::ensure_multi!(errs, height > 0, format!("height {height} must be positive"));
+/// });
+/// 
+/// let ((), errors) = o.finalize();
+/// assert_eq!(errors.peek(), &["width must be positive", "height -3 must be positive"]);
+/// # errors.ignore();
+/// ```
+#[macro_export]
+macro_rules! ensure_multi {
+    ($collector:expr, $cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            #[allow(unused_imports)]
+            use $crate::ErrorCollector as _;
+            $collector.push_error($err);
+        }
+    };
+}
+
+/// Pushes an error into a collector if a condition is `true`.
+/// 
+/// This is the inverse of [`ensure_multi!`], written as `push_if!(collector, cond => err)`. The
+/// error expression is only evaluated if the condition holds.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel};
+/// let mut errors = ErrorSentinel::empty();
+/// for name in ["alice", "", "bob", ""] {
+///     ocm::push_if!(errors, name.is_empty() => "empty name");
+/// }
+/// assert_eq!(errors.error_count(), 2);
+/// # errors.ignore();
+/// ```
+#[macro_export]
+macro_rules! push_if {
+    ($collector:expr, $cond:expr => $err:expr $(,)?) => {
+        if $cond {
+            #[allow(unused_imports)]
+            use $crate::ErrorCollector as _;
+            $collector.push_error($err);
+        }
+    };
+}
+
+/// Pushes an error into a collector, then returns from the current function with an [`Outcome`],
+/// similar to `anyhow::bail!`.
+/// 
+/// With two arguments, `bail_multi!(outcome, err)`, the collector must itself be an [`Outcome`].
+/// The error is pushed into it, and it is returned as-is.
+/// 
+/// With three arguments, `bail_multi!(collector, err, value)`, the error is pushed into the
+/// collector, which is then propagated into a new [`Outcome`] wrapping `value`, which is returned.
+/// The collector is consumed, so must be owned.
+/// 
+/// [`Outcome`]: crate::Outcome
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, Outcome};
+/// fn parse_digits(input: &str) -> Outcome<Vec<u32>, String> {
+///     let mut errors = ErrorSentinel::empty();
+///     if input.is_empty() {
+///         ocm::bail_multi!(errors, "empty input".to_owned(), vec![]);
+///     }
+/// 
+///     let mut digits = vec![];
+///     for c in input.chars() {
+///         match c.to_digit(10) {
+///             Some(d) => digits.push(d),
+///             None if c == '!' => ocm::bail_multi!(errors, "unexpected end".to_owned(), digits),
+///             None => errors.push_error(format!("not a digit: {c}")),
+///         }
+///     }
+/// 
+///     let mut outcome = Outcome::new(digits);
+///     errors.propagate(&mut outcome);
+///     outcome
+/// }
+/// 
+/// let (value, errors) = parse_digits("").finalize();
+/// assert!(value.is_empty());
+/// assert_eq!(errors.peek(), &["empty input"]);
+/// # errors.ignore();
+/// 
+/// let (value, errors) = parse_digits("1x2!3").finalize();
+/// assert_eq!(value, vec![1, 2]);
+/// assert_eq!(errors.peek(), &["not a digit: x", "unexpected end"]);
+/// # errors.ignore();
+/// ```
+/// 
+/// The two-argument form returns an existing [`Outcome`]:
+/// 
+/// ```
+/// # use ocm::Outcome;
+/// fn nonzero(o: Outcome<u32, &'static str>) -> Outcome<u32, &'static str> {
+///     if *o.value() == 0 {
+///         ocm::bail_multi!(o, "value is zero");
+///     }
+///     o
+/// }
+/// 
+/// let (value, errors) = nonzero(Outcome::new(0)).finalize();
+/// assert_eq!(value, 0);
+/// assert_eq!(errors.peek(), &["value is zero"]);
+/// # errors.ignore();
+/// ```
+#[macro_export]
+macro_rules! bail_multi {
+    ($outcome:expr, $err:expr $(,)?) => {{
+        let mut outcome: $crate::Outcome<_, _> = $outcome;
+        outcome.push_error($err);
+        return outcome;
+    }};
+    ($collector:expr, $err:expr, $value:expr $(,)?) => {{
+        use $crate::ErrorCollector as _;
+        let mut collector = $collector;
+        collector.push_error($err);
+        let mut outcome = $crate::Outcome::new($value);
+        collector.propagate(&mut outcome);
+        return outcome;
+    }};
+}
@@ -0,0 +1,60 @@
+use core::{error::Error, fmt::Display};
+
+use alloc::vec::Vec;
+
+/// A collection of errors which implements [`Error`] itself, for returning accumulated errors
+/// through APIs which expect a single error, such as `Box<dyn Error>`.
+/// 
+/// Create one from an [`ErrorSentinel`] with [`into_error`], or from an [`Outcome`] with
+/// [`into_result_error`].
+/// 
+/// The [`Display`] implementation gives a header with the number of errors, followed by each error
+/// on its own line:
+/// 
+/// ```
+/// # use ocm::MultiError;
+/// let error = MultiError(vec!["not a number: x", "not a number: y"]);
+/// assert_eq!(
+///     error.to_string(),
+///     "2 error(s):\n  - not a number: x\n  - not a number: y",
+/// );
+/// ```
+/// 
+/// The [`source`] of a `MultiError` is its first error, if there is one:
+/// 
+/// ```
+/// # use ocm::MultiError;
+/// # use std::error::Error;
+/// let errors = vec!["x".parse::<u32>().unwrap_err(), "".parse::<u32>().unwrap_err()];
+/// let error: Box<dyn Error> = Box::new(MultiError(errors));
+/// 
+/// assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+/// assert!(MultiError::<std::num::ParseIntError>(vec![]).source().is_none());
+/// ```
+/// 
+/// Like [`Outcome::into_result_vec`], the errors are not protected by an [`ErrorSentinel`].
+/// 
+/// [`ErrorSentinel`]: crate::ErrorSentinel
+/// [`Outcome`]: crate::Outcome
+/// [`into_error`]: crate::ErrorSentinel::into_error
+/// [`into_result_error`]: crate::Outcome::into_result_error
+/// [`Outcome::into_result_vec`]: crate::Outcome::into_result_vec
+/// [`source`]: Error::source
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct MultiError<E>(pub Vec<E>);
+
+impl<E: Display> Display for MultiError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} error(s):", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n  - {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error + 'static> Error for MultiError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.first().map(|error| error as &(dyn Error + 'static))
+    }
+}
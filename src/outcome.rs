@@ -1,6 +1,11 @@
-use std::fmt::Debug;
+use core::{cmp::Ordering, convert::Infallible, fmt::Debug, iter::{Product, Sum}, marker::PhantomData};
 
-use crate::{ErrorCollector, ErrorSentinel};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::process::{ExitCode, Termination};
+
+use crate::{ErrorCollector, ErrorContainer, ErrorSentinel, ErrorStats, MultiError, Severity};
 
 /// Contains a value, and any errors produced while obtaining that value.
 /// 
@@ -78,7 +83,8 @@ use crate::{ErrorCollector, ErrorSentinel};
 /// - Transform values and/or errors: [`map`], [`map_errors`]
 /// - Unwrap a value by moving its errors elsewhere: [`propagate`]
 /// - Fold two values and combine their errors: [`integrate`]
-/// - Bundle values into a collection and combine their errors: [`zip`], [`from_iter`]
+/// - Bundle values into a collection and combine their errors: [`zip`], [`zip_all`], [`from_iter`]
+/// - Pick one of two values and combine their errors: [`and`], [`or`]
 /// - Extract the value by asserting there are no errors: [`unwrap`], [`expect`]
 /// 
 /// [`map`]: Outcome::map
@@ -86,13 +92,140 @@ use crate::{ErrorCollector, ErrorSentinel};
 /// [`propagate`]: Outcome::propagate
 /// [`integrate`]: Outcome::integrate
 /// [`zip`]: Outcome::zip
+/// [`zip_all`]: Outcome::zip_all
 /// [`from_iter`]: Outcome::from_iter
+/// [`and`]: Outcome::and
+/// [`or`]: Outcome::or
 /// [`unwrap`]: Outcome::unwrap
 /// [`expect`]: Outcome::expect
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct Outcome<T, E> {
+/// 
+/// # The `?` operator
+/// 
+/// The `?` operator can be used on an `Outcome`, in a function which returns either an `Outcome`
+/// or a `Result<_, ErrorSentinel<E>>`. It behaves like `?` on a [`Result`]:
+/// 
+/// - If the `Outcome` has no errors, `?` evaluates to its value, and execution continues.
+/// - If the `Outcome` has any errors, its value is discarded, and the function returns early with
+///   those errors. In a function returning an `Outcome`, the returned `Outcome` contains the
+///   errors and the [`Default`] value of its value type. In a function returning a `Result`, the
+///   `Err` contains an [`ErrorSentinel`] with the errors.
+/// 
+/// The errors are never dropped along the way, so they must still be handled by the caller.
+/// 
+/// This requires the `try_trait` feature (also enabled by the `nightly` feature), and therefore a
+/// nightly compiler, since the [`Try`] trait is not yet stable.
+/// 
+/// [`Try`]: core::ops::Try
+/// 
+/// ```
+/// # #[cfg(feature = "try_trait")] {
+/// # use ocm::{Outcome, ErrorSentinel};
+/// fn parse_num(s: &str) -> Outcome<u32, String> {
+///     Outcome::from_result(s.parse().map_err(|_| format!("not a number: {s}")))
+/// }
+/// 
+/// fn add(a: &str, b: &str) -> Outcome<u32, String> {
+///     let a = parse_num(a)?;
+///     let b = parse_num(b)?;
+///     Outcome::new(a + b)
+/// }
+/// 
+/// assert_eq!(add("1", "2").unwrap(), 3);
+/// 
+/// // Returns early after parsing `a`, so only one error is reported
+/// let (value, errors) = add("x", "y").finalize();
+/// assert_eq!(value, 0);
+/// assert_eq!(errors.peek(), &["not a number: x"]);
+/// # errors.ignore();
+/// 
+/// // Or, returning a `Result` instead
+/// fn add_result(a: &str, b: &str) -> Result<u32, ErrorSentinel<String>> {
+///     Ok(parse_num(a)? + parse_num(b)?)
+/// }
+/// 
+/// assert_eq!(add_result("1", "2").unwrap(), 3);
+/// let errors = add_result("1", "y").unwrap_err();
+/// assert_eq!(errors.peek(), &["not a number: y"]);
+/// # errors.ignore();
+/// # }
+/// ```
+/// 
+/// `?` can also be used on a [`Result`] in a function which returns an `Outcome`. If the `Result`
+/// is an `Err`, the function returns early with an `Outcome` containing that single error:
+/// 
+/// ```
+/// # #[cfg(feature = "try_trait")] {
+/// # use ocm::Outcome;
+/// fn double(s: &str) -> Outcome<u32, std::num::ParseIntError> {
+///     let n: u32 = s.parse()?;
+///     Outcome::new(n * 2)
+/// }
+/// 
+/// assert_eq!(double("21").unwrap(), 42);
+/// assert_eq!(double("x").len_errors(), 1);
+/// # }
+/// ```
+/// 
+/// Because an `Outcome` must always have a value, even when returning early, an `Outcome` being
+/// returned through `?` must have a value type which implements [`Default`]. The [`Try`] trait also
+/// requires that an `Outcome` which `?` is applied to could itself be returned through `?`, so its
+/// value type must implement `Default` too, even in a function which returns a `Result`.
+/// 
+/// `?` short-circuits on the first `Outcome` with errors, which is at odds with accumulating as
+/// many errors as possible. If you would rather continue and collect all of the errors, use
+/// [`propagate`] instead.
+/// 
+/// # Error containers
+/// 
+/// Errors are stored in a [`Vec`] by default. The optional third type parameter selects a
+/// different [`ErrorContainer`], such as a `BTreeSet` to discard duplicate errors. Most methods
+/// are only available with the default container.
+/// 
+/// # Serialization
+/// 
+/// With the `serde` feature enabled, `Outcome` implements `Serialize` and `Deserialize`. It is
+/// represented as a struct with `value` and `errors` fields.
+/// 
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// # use ocm::Outcome;
+/// let o = Outcome::new_with_errors(42, vec!["invalid digit".to_owned(), "ungültig ✗".to_owned()]);
+/// let json = serde_json::to_string(&o).unwrap();
+/// assert_eq!(json, r#"{"value":42,"errors":["invalid digit","ungültig ✗"]}"#);
+/// assert_eq!(serde_json::from_str::<Outcome<u32, String>>(&json).unwrap(), o);
+/// 
+/// let o: Outcome<u32, String> = Outcome::new(42);
+/// let json = serde_json::to_string(&o).unwrap();
+/// assert_eq!(json, r#"{"value":42,"errors":[]}"#);
+/// assert_eq!(serde_json::from_str::<Outcome<u32, String>>(&json).unwrap(), o);
+/// # }
+/// ```
+/// 
+/// # Fuzzing and property testing
+/// 
+/// With the `arbitrary` feature enabled, which also enables `std`, `Outcome` implements `Arbitrary`,
+/// generating a value and any number of errors. With the `proptest` feature enabled,
+/// `outcome_strategy` can be used to generate `Outcome`s with a bounded number of errors.
+/// 
+/// ```
+/// # #[cfg(feature = "arbitrary")] {
+/// # use ocm::Outcome;
+/// use arbitrary::{Arbitrary, Unstructured};
+/// 
+/// let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+/// let o = Outcome::<u8, u8>::arbitrary(&mut u).unwrap();
+/// // Can be dropped without handling errors, since `Outcome` has no sentinel
+/// drop(o);
+/// # }
+/// ```
+#[derive(Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Outcome<T, E, C = Vec<E>> {
     value: T,
-    errors: Vec<E>,
+    errors: C,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _error: PhantomData<E>,
 }
 
 impl<T, E> Outcome<T, E> {
@@ -106,7 +239,7 @@ impl<T, E> Outcome<T, E> {
     /// ```
     #[must_use]
     pub fn new(value: T) -> Self {
-        Outcome { value, errors: vec![] }
+        Outcome { value, errors: vec![], _error: PhantomData }
     }
 
     /// Constructs a new `Outcome` with some errors.
@@ -118,9 +251,106 @@ impl<T, E> Outcome<T, E> {
     /// ```
     #[must_use]
     pub fn new_with_errors(value: T, errors: Vec<E>) -> Self {
-        Outcome { value, errors }
+        Outcome { value, errors, _error: PhantomData }
+    }
+
+    /// Constructs a new `Outcome` from a value and a list of errors. This is the inverse of
+    /// [`into_parts`], and an alias for [`new_with_errors`].
+    /// 
+    /// [`into_parts`]: Outcome::into_parts
+    /// [`new_with_errors`]: Outcome::new_with_errors
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::from_parts(42, vec!["an error"]);
+    /// assert_eq!(o.into_parts(), (42, vec!["an error"]));
+    /// ```
+    #[must_use]
+    pub fn from_parts(value: T, errors: Vec<E>) -> Self {
+        Self::new_with_errors(value, errors)
     }
     
+    /// Constructs a new `Outcome` from a [`Result`]. If the `Result` is an `Err`, the error is
+    /// added to the `Outcome`, and the value is the [`Default`] value of its type.
+    /// 
+    /// Use [`from_result_or`] to specify a different fallback value.
+    /// 
+    /// [`from_result_or`]: Outcome::from_result_or
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::from_result("42".parse::<u32>());
+    /// assert_eq!(o.unwrap(), 42);
+    /// 
+    /// let o = Outcome::from_result("hello".parse::<u32>());
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 0);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn from_result(result: Result<T, E>) -> Self
+    where T : Default
+    {
+        Self::from_result_or(result, T::default())
+    }
+
+    /// Constructs a new `Outcome` from a [`Result`]. If the `Result` is an `Err`, the error is
+    /// added to the `Outcome`, and the value is `fallback`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::from_result_or("42".parse::<u32>(), 1);
+    /// assert_eq!(o.unwrap(), 42);
+    /// 
+    /// let o = Outcome::from_result_or("hello".parse::<u32>(), 1);
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 1);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn from_result_or(result: Result<T, E>, fallback: T) -> Self {
+        match result {
+            Ok(value) => Outcome::new(value),
+            Err(error) => Outcome::new_with_errors(fallback, vec![error]),
+        }
+    }
+
+    /// Constructs a new `Outcome` from an iterator of [`Result`]s. The values of the `Ok` items
+    /// are collected into the value of the `Outcome`, and the `Err` items become its errors.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o: Outcome<Vec<u32>, _> = Outcome::from_results(
+    ///     ["1", "a", "2", "b", "3"].into_iter().map(|s| s.parse::<u32>())
+    /// );
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(errors.len(), 2);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn from_results<I, V>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<V, E>>,
+        T: FromIterator<V>,
+    {
+        let mut errors = vec![];
+        let value = iter.into_iter()
+            .filter_map(|result| match result {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    errors.push(error);
+                    None
+                }
+            })
+            .collect();
+
+        Outcome::new_with_errors(value, errors)
+    }
+
     /// A convenience function to construct a new `Outcome` by accumulating errors over time, and
     /// finally returning some value.
     /// 
@@ -158,41 +388,381 @@ impl<T, E> Outcome<T, E> {
         sentinel.into_outcome(value)
     }
 
-    /// Adds a new error to this `Outcome`.
+    /// Like [`build`], but starts from an initial value which the closure can mutate, rather than
+    /// computing the value from scratch. The initial value, after any mutations, becomes the value
+    /// of the new `Outcome`.
+    /// 
+    /// [`build`]: Outcome::build
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// let o = Outcome::build_with(vec![], |names, errs| {
+    ///     for name in ["alice", "", "bob", ""] {
+    ///         if name.is_empty() {
+    ///             errs.push_error(format!("empty name at position {}", names.len()));
+    ///         } else {
+    ///             names.push(name.to_owned());
+    ///         }
+    ///     }
+    /// });
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec!["alice", "bob"]);
+    /// assert_eq!(errors.peek(), &["empty name at position 1", "empty name at position 2"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn build_with<F>(mut value: T, func: F) -> Self
+    where
+        F: FnOnce(&mut T, &mut ErrorSentinel<E>),
+    {
+        let mut sentinel = ErrorSentinel::empty();
+        func(&mut value, &mut sentinel);
+        sentinel.into_outcome(value)
+    }
+
+    /// Like [`build`], but the closure only accumulates errors, and the value of the new `Outcome`
+    /// is the [`Default`] value of its type.
+    /// 
+    /// This is useful for procedures which have no meaningful value, such as validation. See also
+    /// [`check`], a shorthand for outcomes whose value is `()`.
+    /// 
+    /// [`build`]: Outcome::build
+    /// [`check`]: Outcome::check
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// let o: Outcome<Vec<char>, &str> = Outcome::build_default(|errs| {
+    ///     errs.push_error("nothing to see here");
+    /// });
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![]);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn build_default<F>(func: F) -> Self
+    where
+        T: Default,
+        F: FnOnce(&mut ErrorSentinel<E>),
+    {
+        Self::build_with(T::default(), |_, errs| func(errs))
+    }
+
+    /// Like [`build`], but the closure returns a [`Result`] so that it can abort early when an
+    /// unrecoverable error occurs.
+    /// 
+    /// If the closure returns `Ok`, the value of the new `Outcome` is `Some`. If it returns `Err`,
+    /// the error is added after any errors which were already accumulated, and the value is `None`.
+    /// 
+    /// [`build`]: Outcome::build
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// fn parse_all(input: &[&str]) -> Outcome<Option<Vec<u32>>, String> {
+    ///     Outcome::try_build(|errs| {
+    ///         let mut nums = vec![];
+    ///         for item in input {
+    ///             if item.is_empty() {
+    ///                 // Can't continue from this
+    ///                 return Err("unexpected end of input".to_owned());
+    ///             }
+    ///             match item.parse() {
+    ///                 Ok(num) => nums.push(num),
+    ///                 Err(_) => errs.push_error(format!("not a number: {item}")),
+    ///             }
+    ///         }
+    ///         Ok(nums)
+    ///     })
+    /// }
+    /// 
+    /// // Happy path
+    /// let (value, errors) = parse_all(&["1", "x", "3"]).finalize();
+    /// assert_eq!(value, Some(vec![1, 3]));
+    /// assert_eq!(errors.peek(), &["not a number: x"]);
+    /// # errors.ignore();
+    /// 
+    /// // Bailing out early keeps the errors from before the bail
+    /// let (value, errors) = parse_all(&["1", "x", "", "y"]).finalize();
+    /// assert_eq!(value, None);
+    /// assert_eq!(errors.peek(), &["not a number: x", "unexpected end of input"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn try_build<F>(func: F) -> Outcome<Option<T>, E>
+    where
+        F: FnOnce(&mut ErrorSentinel<E>) -> Result<T, E>,
+    {
+        let mut sentinel = ErrorSentinel::empty();
+        let value = match func(&mut sentinel) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                sentinel.push_error(error);
+                None
+            }
+        };
+        sentinel.into_outcome(value)
+    }
+
+    /// Like [`try_build`], but uses `fallback` as the value if the closure returns an `Err`, rather
+    /// than wrapping the value in an [`Option`].
+    /// 
+    /// This allows `?` to be used on ordinary [`Result`]s within the closure, short-circuiting on
+    /// the first such failure, while also accumulating errors in the sentinel. The error from the
+    /// `Err` is pushed after any errors which were accumulated before the early return.
+    /// 
+    /// [`try_build`]: Outcome::try_build
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// fn sum_all(input: &[&str]) -> Outcome<u32, String> {
+    ///     Outcome::build_with_result(0, |errs| {
+    ///         let mut sum = 0;
+    ///         for item in input {
+    ///             if item.is_empty() {
+    ///                 return Err("unexpected end of input".to_owned());
+    ///             }
+    ///             let num: i32 = item.parse().map_err(|_| format!("not a number: {item}"))?;
+    ///             match u32::try_from(num) {
+    ///                 Ok(num) => sum += num,
+    ///                 Err(_) => errs.push_error(format!("negative: {item}")),
+    ///             }
+    ///         }
+    ///         Ok(sum)
+    ///     })
+    /// }
+    /// 
+    /// let (value, errors) = sum_all(&["1", "-2", "3"]).finalize();
+    /// assert_eq!(value, 4);
+    /// assert_eq!(errors.peek(), &["negative: -2"]);
+    /// # errors.ignore();
+    /// 
+    /// let (value, errors) = sum_all(&["1", "-2", "x", "3"]).finalize();
+    /// assert_eq!(value, 0);
+    /// assert_eq!(errors.peek(), &["negative: -2", "not a number: x"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn build_with_result<F>(fallback: T, func: F) -> Outcome<T, E>
+    where
+        F: FnOnce(&mut ErrorSentinel<E>) -> Result<T, E>,
+    {
+        Self::try_build(func).map(|value| value.unwrap_or(fallback))
+    }
+
+    /// Like [`build`], but the closure is asynchronous. This allows errors to be accumulated while
+    /// computing a value which requires awaiting, such as fetching remote resources.
+    /// 
+    /// The closure is an [async closure], so the future it returns may hold onto the mutable borrow
+    /// of the sentinel across `.await` points. The sentinel itself is owned by the future returned
+    /// from this function, so it lives for as long as the closure's future does.
+    /// 
+    /// If the returned future is dropped before completion, for example because it was cancelled
+    /// by a timeout, the sentinel is dropped with it. If no errors had been pushed yet, nothing is
+    /// lost and the sentinel is considered handled. Otherwise, the errors which were accumulated so
+    /// far are reported as unhandled, which panics unless a hook has been installed.
+    /// 
+    /// [`build`]: Outcome::build
+    /// [async closure]: core::ops::AsyncFnOnce
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// async fn fetch(name: &str) -> Result<u32, String> {
+    ///     name.parse().map_err(|_| format!("could not fetch {name}"))
+    /// }
+    /// 
+    /// let o = Outcome::build_async(async |errs| {
+    ///     let mut total = 0;
+    ///     for name in ["1", "two", "3"] {
+    ///         match fetch(name).await {
+    ///             Ok(value) => total += value,
+    ///             Err(error) => errs.push_error(error),
+    ///         }
+    ///     }
+    ///     total
+    /// });
+    /// 
+    /// let (value, errors) = futures::executor::block_on(o).finalize();
+    /// assert_eq!(value, 4);
+    /// assert_eq!(errors.peek(), &["could not fetch two"]);
+    /// # errors.ignore();
+    /// ```
     /// 
     /// ```
     /// # use ocm::Outcome;
-    /// let mut o = Outcome::new(42);
-    /// o.push_error("oh no!");
+    /// use futures::FutureExt;
     /// 
-    /// assert!(o.has_errors());
+    /// // Cancelled before any errors were pushed, so this is fine
+    /// let o = Outcome::<(), &str>::build_async(async |_errs| futures::future::pending().await);
+    /// assert!(o.now_or_never().is_none());
     /// ```
-    pub fn push_error(&mut self, error: E) {
-        self.errors.push(error);
+    /// 
+    /// ```should_panic
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// use futures::FutureExt;
+    /// 
+    /// let o = Outcome::<(), &str>::build_async(async |errs| {
+    ///     errs.push_error("oh no!");
+    ///     futures::future::pending().await
+    /// });
+    /// o.now_or_never(); // Panics!
+    /// ```
+    pub async fn build_async<F>(func: F) -> Self
+    where
+        F: AsyncFnOnce(&mut ErrorSentinel<E>) -> T,
+    {
+        let mut guard = CancelGuard(Some(ErrorSentinel::empty()));
+        let value = func(guard.0.as_mut().unwrap()).await;
+        guard.0.take().unwrap().into_outcome(value)
     }
 
-    /// Moves the errors from this `Outcome` into an [`ErrorCollector`], and unwraps it to return
-    /// its value.
+    /// Runs a sequence of validators against the value of this `Outcome`, adding an error for each
+    /// validator which returns one. Existing errors are preserved, and new errors are added after
+    /// them in the same order as the validators.
     /// 
     /// ```
     /// # use ocm::Outcome;
-    /// let mut source = Outcome::new(42);
-    /// source.push_error("oh no!");
-    /// source.push_error("another error!");
-    /// let mut dest = Outcome::new(123);
-    /// dest.push_error("one last failure!");
+    /// let o = Outcome::new_with_errors("hello world", vec!["existing error".to_owned()]);
     /// 
-    /// let source_value = source.propagate(&mut dest);
-    /// assert_eq!(dest.len_errors(), 3);
-    /// assert_eq!(source_value, 42);
+    /// let validated = o.validate([
+    ///     |s: &&str| (s.len() > 5).then(|| format!("'{s}' is too long")),
+    ///     |s: &&str| s.contains(' ').then(|| format!("'{s}' contains a space")),
+    ///     |s: &&str| s.is_empty().then(|| "string is empty".to_owned()),
+    /// ]);
+    /// 
+    /// let (value, errors) = validated.finalize();
+    /// assert_eq!(value, "hello world");
+    /// assert_eq!(errors.peek(), &[
+    ///     "existing error",
+    ///     "'hello world' is too long",
+    ///     "'hello world' contains a space",
+    /// ]);
+    /// # errors.ignore();
     /// ```
-    #[must_use = "propagate returns the inner value; use `integrate` if you wish to merge values in-place"]
-    pub fn propagate(self, other: &mut impl ErrorCollector<E>) -> T {
-        for error in self.errors.into_iter() {
-            other.push_error(error);
+    #[must_use]
+    pub fn validate<V>(mut self, validators: impl IntoIterator<Item = V>) -> Self
+    where
+        V: Fn(&T) -> Option<E>,
+    {
+        for validator in validators {
+            if let Some(error) = validator(&self.value) {
+                self.push_error(error);
+            }
         }
 
-        self.value
+        self
+    }
+
+    /// Checks the value of this `Outcome` against a predicate. If the predicate fails, an error is
+    /// added after any existing errors, and the value is replaced with a fallback.
+    /// 
+    /// Unlike [`validate`], this rejects values which fail the check, so that subsequent code can
+    /// rely on the value meeting the predicate.
+    /// 
+    /// [`validate`]: Outcome::validate
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(-5, vec!["existing error"]);
+    /// let checked = o.check_value(|n| *n >= 0, || "value is negative", || 0);
+    /// 
+    /// let (value, errors) = checked.finalize();
+    /// assert_eq!(value, 0);
+    /// assert_eq!(errors.peek(), &["existing error", "value is negative"]);
+    /// # errors.ignore();
+    /// 
+    /// // Values which pass the check are left alone
+    /// let o: Outcome<_, &str> = Outcome::new(5);
+    /// assert_eq!(o.check_value(|n| *n >= 0, || "value is negative", || 0).unwrap(), 5);
+    /// ```
+    #[must_use]
+    pub fn check_value(
+        mut self,
+        pred: impl FnOnce(&T) -> bool,
+        on_fail: impl FnOnce() -> E,
+        fallback: impl FnOnce() -> T,
+    ) -> Self {
+        if !pred(&self.value) {
+            self.push_error(on_fail());
+            self.value = fallback();
+        }
+
+        self
+    }
+
+    /// Consumes this `Outcome`, returning its value and an iterator over its errors, so that the
+    /// errors can be consumed inline without needing a collector to propagate them into.
+    /// 
+    /// Like [`into_parts`], the errors are no longer protected by an [`ErrorSentinel`], so it is
+    /// up to the caller to deal with them.
+    /// 
+    /// [`into_parts`]: Outcome::into_parts
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["oh no!", "another error!"]);
+    /// 
+    /// let mut log = vec![];
+    /// let (value, errors) = o.propagate_errors_only();
+    /// log.extend(errors.map(|e| format!("warning: {e}")));
+    /// 
+    /// assert_eq!(value, 42);
+    /// assert_eq!(log, vec!["warning: oh no!", "warning: another error!"]);
+    /// ```
+    #[must_use]
+    pub fn propagate_errors_only(self) -> (T, vec::IntoIter<E>) {
+        (self.value, self.errors.into_iter())
+    }
+
+    /// Pushes the value of this `Outcome` onto the end of a [`Vec`], and moves its errors into an
+    /// [`ErrorCollector`].
+    /// 
+    /// This is useful for incrementally assembling a large set of results without building up
+    /// intermediate `Outcome`s.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorSentinel, Outcome};
+    /// let mut values = vec![];
+    /// let mut errors = ErrorSentinel::empty();
+    /// for input in ["1", "x", "3"] {
+    ///     let o = match input.parse::<u32>() {
+    ///         Ok(n) => Outcome::new(n),
+    ///         Err(_) => Outcome::new_with_errors(0, vec![format!("bad number: {input}")]),
+    ///     };
+    ///     o.collect_into(&mut values, &mut errors);
+    /// }
+    /// 
+    /// assert_eq!(values, vec![1, 0, 3]);
+    /// assert_eq!(errors.peek(), &["bad number: x"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn collect_into(self, values: &mut Vec<T>, errors: &mut (impl ErrorCollector<E> + ?Sized)) {
+        values.push(self.propagate(errors));
+    }
+
+    /// Consumes this `Outcome`, returning its value and its errors moved into a new collector, such
+    /// as a [`BTreeSet`] or (with the `std` feature) a `HashSet`, which discards duplicate errors.
+    /// 
+    /// Like [`into_parts`], the errors are no longer protected by an [`ErrorSentinel`].
+    /// 
+    /// [`BTreeSet`]: alloc::collections::BTreeSet
+    /// [`into_parts`]: Outcome::into_parts
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// # use std::collections::BTreeSet;
+    /// let o = Outcome::new_with_errors(42, vec!["missing file", "bad input", "missing file"]);
+    /// let (value, errors): (_, BTreeSet<_>) = o.dedup_into_set();
+    /// 
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors.into_iter().collect::<Vec<_>>(), vec!["bad input", "missing file"]);
+    /// ```
+    #[must_use]
+    pub fn dedup_into_set<S: ErrorCollector<E> + Default>(self) -> (T, S) {
+        let mut set = S::default();
+        let value = self.propagate(&mut set);
+        (value, set)
     }
 
     /// Moves the errors from this `Outcome` into another `Outcome`, and apply a mapping function
@@ -246,24 +816,279 @@ impl<T, E> Outcome<T, E> {
         )
     }
 
-    /// Applies a function to the value within this `Outcome`.
+    /// Like [`zip`], but merges the errors of both `Outcome`s using a comparison function, rather
+    /// than placing this `Outcome`'s errors first.
+    /// 
+    /// If both lists of errors are already sorted according to `cmp`, the combined errors will be
+    /// sorted too. When two errors compare equal, the one from this `Outcome` comes first.
+    /// 
+    /// [`zip`]: Outcome::zip
     /// 
     /// ```
     /// # use ocm::Outcome;
-    /// let o = Outcome::new_with_errors("Hello".to_owned(), vec!["oh no!"]);
-    /// let o_rev = o.map(|s| s.len());
+    /// // Errors from two parts of a file, sorted by line number
+    /// let a = Outcome::new_with_errors("header", vec![(1, "a"), (5, "b"), (9, "c")]);
+    /// let b = Outcome::new_with_errors("body", vec![(2, "d"), (5, "e"), (12, "f")]);
     /// 
-    /// let (value, errors) = o_rev.finalize();
-    /// assert_eq!(value, 5);
-    /// assert_eq!(errors.len(), 1);
+    /// let (value, errors) = a.zip_by(b, |x, y| x.0.cmp(&y.0)).finalize();
+    /// assert_eq!(value, ("header", "body"));
+    /// assert_eq!(errors.peek(), &[(1, "a"), (2, "d"), (5, "b"), (5, "e"), (9, "c"), (12, "f")]);
     /// # errors.ignore();
     /// ```
     #[must_use]
-    pub fn map<R>(self, func: impl FnOnce(T) -> R) -> Outcome<R, E> {
-        Outcome::new_with_errors(
-            func(self.value),
-            self.errors,
-        )
+    pub fn zip_by<OT>(self, other: Outcome<OT, E>, mut cmp: impl FnMut(&E, &E) -> Ordering) -> Outcome<(T, OT), E> {
+        let mut errors = Vec::with_capacity(self.errors.len() + other.errors.len());
+        let mut these = self.errors.into_iter().peekable();
+        let mut others = other.errors.into_iter().peekable();
+        loop {
+            let take_this = match (these.peek(), others.peek()) {
+                (Some(this), Some(other)) => cmp(this, other) != Ordering::Greater,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            errors.extend(if take_this { these.next() } else { others.next() });
+        }
+
+        Outcome::new_with_errors((self.value, other.value), errors)
+    }
+
+    /// Consumes this `Outcome` and another one, returning a new `Outcome` with the other's value,
+    /// and the errors of both combined, with this `Outcome`'s errors first.
+    /// 
+    /// Unlike [`Result::and`], errors in this `Outcome` do not prevent the other value from being
+    /// used, and no errors are ever discarded.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let a = Outcome::new_with_errors(5, vec!["error 1"]);
+    /// let b = Outcome::new_with_errors("hello", vec!["error 2"]);
+    /// 
+    /// let (value, errors) = a.and(b).finalize();
+    /// assert_eq!(value, "hello");
+    /// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn and<U>(self, other: Outcome<U, E>) -> Outcome<U, E> {
+        self.zip(other).map(|(_, value)| value)
+    }
+
+    /// Consumes this `Outcome` and another one, returning a new `Outcome` with this value if this
+    /// `Outcome` is a success, or the other's value otherwise. The errors of both are combined,
+    /// with this `Outcome`'s errors first.
+    /// 
+    /// This is useful for preferring one value but falling back to another. Unlike [`Result::or`],
+    /// no errors are ever discarded, even when falling back.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let preferred = Outcome::new_with_errors(1, vec!["error 1"]);
+    /// let fallback = Outcome::new_with_errors(2, vec!["error 2"]);
+    /// 
+    /// let (value, errors) = preferred.or(fallback).finalize();
+    /// assert_eq!(value, 2);
+    /// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+    /// # errors.ignore();
+    /// 
+    /// let preferred = Outcome::new(1);
+    /// let fallback = Outcome::new_with_errors(2, vec!["error 2"]);
+    /// 
+    /// let (value, errors) = preferred.or(fallback).finalize();
+    /// assert_eq!(value, 1);
+    /// assert_eq!(errors.peek(), &["error 2"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn or(self, other: Outcome<T, E>) -> Outcome<T, E> {
+        let use_self = self.is_success();
+        self.zip(other).map(|(this, other)| if use_self { this } else { other })
+    }
+
+    /// Consumes a fixed-size array of `Outcome`s, returning a new `Outcome` with their values as an
+    /// array of the same size, and the errors combined in index order.
+    /// 
+    /// Unlike collecting into an `Outcome<Vec<T>, E>` with [`from_iter`], the values are never
+    /// moved into a heap allocation.
+    /// 
+    /// [`from_iter`]: Outcome::from_iter
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let zipped = Outcome::zip_all([
+    ///     Outcome::new_with_errors(1, vec!["error 1"]),
+    ///     Outcome::new(2),
+    ///     Outcome::new_with_errors(3, vec!["error 2", "error 3"]),
+    ///     Outcome::new(4),
+    /// ]);
+    /// 
+    /// let (value, errors) = zipped.finalize();
+    /// assert_eq!(value, [1, 2, 3, 4]);
+    /// assert_eq!(errors.peek(), &["error 1", "error 2", "error 3"]);
+    /// # errors.ignore();
+    /// ```
+    /// 
+    /// The values do not need to implement [`Clone`], and arrays of any length are supported:
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// #[derive(Debug, PartialEq)]
+    /// struct NotClone(u32);
+    /// 
+    /// let one = Outcome::zip_all([Outcome::new_with_errors(NotClone(1), vec!["error"])]);
+    /// assert_eq!(one.len_errors(), 1);
+    /// let (value, errors) = one.finalize();
+    /// assert_eq!(value, [NotClone(1)]);
+    /// # errors.ignore();
+    /// 
+    /// let none = Outcome::<NotClone, &str>::zip_all([]);
+    /// assert!(none.is_success());
+    /// assert_eq!(none.unwrap(), []);
+    /// ```
+    #[must_use]
+    pub fn zip_all<const N: usize>(outcomes: [Outcome<T, E>; N]) -> Outcome<[T; N], E> {
+        let mut errors = vec![];
+        let values = outcomes.map(|outcome| {
+            errors.extend(outcome.errors);
+            outcome.value
+        });
+
+        Outcome::new_with_errors(values, errors)
+    }
+
+    /// Attempts an operation up to `attempts` times, stopping at the first success. The error from
+    /// each failed attempt is kept, so that problems are still surfaced even if a later attempt
+    /// succeeds.
+    /// 
+    /// The closure is passed the index of the attempt, starting from 0. If every attempt fails,
+    /// the value is `None`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// fn flaky(succeed_on: usize) -> impl FnMut(usize) -> Result<u32, String> {
+    ///     move |attempt| if attempt == succeed_on {
+    ///         Ok(42)
+    ///     } else {
+    ///         Err(format!("attempt {attempt} failed"))
+    ///     }
+    /// }
+    /// 
+    /// // Success on the first try
+    /// let o = Outcome::retry(5, flaky(0));
+    /// assert_eq!(o.unwrap(), Some(42));
+    /// 
+    /// // Success on the third try
+    /// let (value, errors) = Outcome::retry(5, flaky(2)).finalize();
+    /// assert_eq!(value, Some(42));
+    /// assert_eq!(errors.peek(), &["attempt 0 failed", "attempt 1 failed"]);
+    /// # errors.ignore();
+    /// 
+    /// // Every attempt fails
+    /// let (value, errors) = Outcome::retry(3, flaky(10)).finalize();
+    /// assert_eq!(value, None);
+    /// assert_eq!(errors.len(), 3);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn retry(attempts: usize, mut func: impl FnMut(usize) -> Result<T, E>) -> Outcome<Option<T>, E> {
+        let mut errors = vec![];
+        for attempt in 0..attempts {
+            match func(attempt) {
+                Ok(value) => return Outcome::new_with_errors(Some(value), errors),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Outcome::new_with_errors(None, errors)
+    }
+
+    /// Applies a function to the value within this `Outcome`, which may also add new errors.
+    /// 
+    /// The closure is given an [`ErrorCollector`] for any new errors, which are added after the
+    /// existing ones. This is useful when the transformation itself can discover problems.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors("a long name".to_owned(), vec!["oh no!".to_owned()]);
+    /// let truncated = o.map_with_errors(|mut s, errs| {
+    ///     if s.len() > 6 {
+    ///         errs.push_error(format!("'{s}' was truncated"));
+    ///         s.truncate(6);
+    ///     }
+    ///     s
+    /// });
+    /// 
+    /// let (value, errors) = truncated.finalize();
+    /// assert_eq!(value, "a long");
+    /// assert_eq!(errors.peek(), &["oh no!", "'a long name' was truncated"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn map_with_errors<R>(self, func: impl FnOnce(T, &mut dyn ErrorCollector<E>) -> R) -> Outcome<R, E> {
+        let mut sentinel = ErrorSentinel::new(self.errors);
+        let value = func(self.value, &mut sentinel);
+        sentinel.into_outcome(value)
+    }
+
+    /// Runs a step which mutates the value within this `Outcome` in-place, and may add new errors
+    /// after the existing ones. Returns `self` so that several steps can be chained together.
+    /// 
+    /// Unlike [`map_with_errors`], the value is not moved, which can be cheaper for large values.
+    /// 
+    /// [`map_with_errors`]: Outcome::map_with_errors
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// let o = Outcome::new_with_errors(vec![3, -1, 2, -5], vec!["parse error".to_owned()])
+    ///     .then(|nums, errs| {
+    ///         for n in nums.iter_mut().filter(|n| **n < 0) {
+    ///             errs.push_error(format!("negative number {n}"));
+    ///             *n = 0;
+    ///         }
+    ///     })
+    ///     .then(|nums, errs| {
+    ///         nums.sort();
+    ///         if nums.len() > 3 {
+    ///             errs.push_error("too many numbers".to_owned());
+    ///         }
+    ///     });
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![0, 0, 2, 3]);
+    /// assert_eq!(errors.peek(), &[
+    ///     "parse error",
+    ///     "negative number -1",
+    ///     "negative number -5",
+    ///     "too many numbers",
+    /// ]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn then(mut self, func: impl FnOnce(&mut T, &mut ErrorSentinel<E>)) -> Self {
+        let mut sentinel = ErrorSentinel::empty();
+        func(&mut self.value, &mut sentinel);
+        sentinel.propagate(&mut self);
+        self
+    }
+
+    /// Mutates the value within this `Outcome` in-place, leaving the errors untouched.
+    /// 
+    /// Unlike [`map`], the value is not moved, so this can be cheaper for large values.
+    /// 
+    /// [`map`]: Outcome::map
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(vec![3, 1, 2], vec!["oh no!"]);
+    /// o.map_in_place(|nums| nums.sort());
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(errors.peek(), &["oh no!"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn map_in_place(&mut self, func: impl FnOnce(&mut T)) {
+        func(&mut self.value);
     }
 
     /// Applies a function to the errors within this `Outcome`.
@@ -286,6 +1111,108 @@ impl<T, E> Outcome<T, E> {
         )
     }
 
+    /// Mutates each of the errors within this `Outcome` in-place, leaving the value untouched.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(42, vec!["oh no!".to_owned(), "uh oh".to_owned()]);
+    /// o.map_errors_in_place(|e| e.insert_str(0, "error: "));
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors.peek(), &["error: oh no!", "error: uh oh"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn map_errors_in_place(&mut self, func: impl FnMut(&mut E)) {
+        self.errors.iter_mut().for_each(func);
+    }
+
+    /// Removes the errors within this `Outcome` which don't match a predicate, keeping the rest in
+    /// their original order.
+    /// 
+    /// This is useful for allow-lists, where some kinds of error should be ignored.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(42, vec!["lint: unused", "type mismatch", "lint: style"]);
+    /// o.retain_errors(|e| !e.starts_with("lint"));
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors.peek(), &["type mismatch"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn retain_errors(&mut self, pred: impl FnMut(&E) -> bool) {
+        self.errors.retain(pred);
+    }
+
+    /// Applies one function to the value within this `Outcome`, and another to each of its errors.
+    /// 
+    /// This is equivalent to calling [`map`] and then [`map_errors`].
+    /// 
+    /// [`map`]: Outcome::map
+    /// [`map_errors`]: Outcome::map_errors
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors("Hello", vec![404, 500]);
+    /// let mapped = o.bimap(|s| s.len(), |code| format!("HTTP {code}"));
+    /// 
+    /// let (value, errors) = mapped.finalize();
+    /// assert_eq!(value, 5);
+    /// assert_eq!(errors.peek(), &["HTTP 404", "HTTP 500"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn bimap<R, RE>(self, value_func: impl FnOnce(T) -> R, error_func: impl FnMut(E) -> RE) -> Outcome<R, RE> {
+        Outcome::new_with_errors(
+            value_func(self.value),
+            self.errors.into_iter().map(error_func).collect(),
+        )
+    }
+
+    /// Converts from `&Outcome<T, E>` to `Outcome<&T, &E>`, borrowing the value and each error.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors("Hello".to_owned(), vec!["oh no!"]);
+    /// let len = o.as_ref().map(|s| s.len());
+    /// 
+    /// assert_eq!(len.len_errors(), 1);
+    /// assert_eq!(o.len_errors(), 1); // `o` is still usable
+    /// # len.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn as_ref(&self) -> Outcome<&T, &E> {
+        Outcome::new_with_errors(&self.value, self.errors.iter().collect())
+    }
+
+    /// Erases the type of the errors within this `Outcome`, by boxing each of them into a
+    /// [`Error`](core::error::Error) trait object.
+    /// 
+    /// This is useful for unifying the errors of sub-tasks which produce different error types.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let ints = Outcome::new_with_errors(12, vec!["x".parse::<u32>().unwrap_err()]);
+    /// let floats = Outcome::new_with_errors(1.5, vec!["y".parse::<f32>().unwrap_err()]);
+    /// 
+    /// // `ints` and `floats` have different error types, so can't be zipped directly
+    /// let zipped = ints.into_dyn().zip(floats.into_dyn());
+    /// 
+    /// let (value, errors) = zipped.finalize();
+    /// assert_eq!(value, (12, 1.5));
+    /// assert_eq!(errors.peek()[0].to_string(), "invalid digit found in string");
+    /// assert_eq!(errors.peek()[1].to_string(), "invalid float literal");
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn into_dyn(self) -> Outcome<T, Box<dyn core::error::Error + Send + Sync>>
+    where E : core::error::Error + Send + Sync + 'static
+    {
+        self.map_errors(|e| Box::new(e) as _)
+    }
+
     /// Extracts the inner value, panicking if there are any errors.
     /// 
     /// The panic message includes the [`Debug`] representation of the errors. If you would like
@@ -316,6 +1243,35 @@ impl<T, E> Outcome<T, E> {
         }
     }
 
+    /// Extracts the inner value, asserting that there are no errors and panicking otherwise.
+    /// 
+    /// This is equivalent to [`unwrap`], but with a clearer name. The panic message includes the
+    /// number of errors and their [`Debug`] representation.
+    /// 
+    /// [`unwrap`]: Outcome::unwrap
+    /// 
+    /// ```should_panic(expected = "expected no errors, found 2")
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// o.assert_empty(); // Panics with: expected no errors, found 2: ["error 1", "error 2"]
+    /// ```
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o: Outcome<_, String> = Outcome::new(42);
+    /// assert_eq!(o.assert_empty(), 42);
+    /// ```
+    #[track_caller]
+    pub fn assert_empty(self) -> T
+    where E : Debug
+    {
+        if self.is_success() {
+            self.value
+        } else {
+            panic!("expected no errors, found {}: {:?}", self.errors.len(), self.errors)
+        }
+    }
+
     /// Extracts the inner value, panicking with a message if there are any errors.
     /// 
     /// ```should_panic
@@ -341,6 +1297,64 @@ impl<T, E> Outcome<T, E> {
         }
     }
 
+    /// Extracts the errors, discarding the value, and panicking if there are no errors.
+    /// 
+    /// This is the opposite of [`unwrap`], and is mostly useful in tests. The panic message
+    /// includes the [`Debug`] representation of the value. If you would like to provide a custom
+    /// message instead, use [`expect_errors`].
+    /// 
+    /// [`unwrap`]: Outcome::unwrap
+    /// [`expect_errors`]: Outcome::expect_errors
+    /// 
+    /// ```should_panic
+    /// # use ocm::Outcome;
+    /// let o: Outcome<_, String> = Outcome::new(42);
+    /// o.unwrap_errors(); // Panics
+    /// ```
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let errors = o.unwrap_errors();
+    /// assert_eq!(errors, vec!["error 1", "error 2"]);
+    /// ```
+    #[track_caller]
+    pub fn unwrap_errors(self) -> Vec<E>
+    where T : Debug
+    {
+        if self.has_errors() {
+            self.errors
+        } else {
+            panic!("called `unwrap_errors` on an Outcome without errors: {:?}", self.value)
+        }
+    }
+
+    /// Extracts the errors, discarding the value, and panicking with a message if there are no
+    /// errors.
+    /// 
+    /// ```should_panic
+    /// # use ocm::Outcome;
+    /// let o: Outcome<_, String> = Outcome::new(42);
+    /// o.expect_errors("parsing should have failed"); // Panics
+    /// ```
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let errors = o.expect_errors("parsing should have failed");
+    /// assert_eq!(errors, vec!["error 1", "error 2"]);
+    /// ```
+    #[track_caller]
+    pub fn expect_errors(self, msg: &str) -> Vec<E>
+    where T : Debug
+    {
+        if self.has_errors() {
+            self.errors
+        } else {
+            panic!("{msg}")
+        }
+    }
+
     /// Converts this `Outcome` into a [`Result`]:
     /// 
     /// - If there are no errors, produces an [`Ok`] with the value.
@@ -357,86 +1371,1120 @@ impl<T, E> Outcome<T, E> {
         }
     }
 
-    /// Converts this `Outcome` into an [`ErrorSentinel`], discarding the value.
+    /// Converts this `Outcome` into a [`Result`] with a plain [`Vec`] of errors:
     /// 
-    /// You **must** handle the errors before they are dropped, as with [`finalize`].
+    /// - If there are no errors, produces an [`Ok`] with the value.
+    /// - Otherwise, produces an [`Err`] with the errors, discarding the value.
     /// 
-    /// [`finalize`]: Outcome::finalize
-    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
-    pub fn into_errors(self) -> ErrorSentinel<E> {
-        ErrorSentinel::new(self.errors)
-    }
-
-    /// Returns `true` if this `Outcome` has any errors.
+    /// Unlike [`into_result`], the errors are not wrapped in an [`ErrorSentinel`], so there is no
+    /// guarantee that they are handled. Prefer `into_result` unless you need to interface with an
+    /// API which requires a `Vec`.
     /// 
-    /// Opposite of [`is_success`](#method.is_success).
-    #[must_use]
-    pub fn has_errors(&self) -> bool {
-        self.len_errors() > 0
-    }
-
-    /// Returns `true` if this `Outcome` has no errors.
+    /// This conversion is also available through [`From`].
     /// 
-    /// Opposite of [`has_errors`](#method.has_errors).
-    #[must_use]
-    pub fn is_success(&self) -> bool {
-        self.len_errors() == 0
-    }
-
-    /// The number of errors within this `Outcome`.
+    /// [`into_result`]: Outcome::into_result
     /// 
     /// ```
     /// # use ocm::Outcome;
-    /// let mut o = Outcome::new(42);
-    /// o.push_error("this went wrong");
-    /// o.push_error("that went wrong");
+    /// let o: Outcome<_, &str> = Outcome::new(42);
+    /// assert_eq!(o.into_result_vec(), Ok(42));
     /// 
-    /// assert_eq!(o.len_errors(), 2);
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// assert_eq!(o.into_result_vec(), Err(vec!["error 1", "error 2"]));
     /// ```
-    #[must_use]
-    pub fn len_errors(&self) -> usize {
-        self.errors.len()
-    }
+    pub fn into_result_vec(self) -> Result<T, Vec<E>> {
+        if self.is_success() {
+            Ok(self.value)
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Converts this `Outcome` into a [`Result`] with a [`MultiError`], which implements
+    /// [`Error`](core::error::Error):
+    /// 
+    /// - If there are no errors, produces an [`Ok`] with the value.
+    /// - Otherwise, produces an [`Err`] with the errors, discarding the value.
+    /// 
+    /// This is useful at boundaries which expect a single error, such as a `main` function
+    /// returning `Result<(), Box<dyn Error>>`.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, MultiError};
+    /// # use std::error::Error;
+    /// fn parse_all(input: &[&str]) -> Outcome<Vec<u32>, std::num::ParseIntError> {
+    ///     input.iter().map(|s| Outcome::from_result_or(s.parse(), 0)).collect()
+    /// }
+    /// 
+    /// fn run() -> Result<u32, Box<dyn Error>> {
+    ///     let nums = parse_all(&["1", "x", "3"]).into_result_error()?;
+    ///     Ok(nums.iter().sum())
+    /// }
+    /// 
+    /// assert_eq!(
+    ///     run().unwrap_err().to_string(),
+    ///     "1 error(s):\n  - invalid digit found in string",
+    /// );
+    /// ```
+    pub fn into_result_error(self) -> Result<T, MultiError<E>> {
+        self.into_result_vec().map_err(MultiError)
+    }
+
+    /// Converts this `Outcome` into an [`anyhow::Result`]. If there are any errors, they are
+    /// collected into a [`MultiError`] which is wrapped in an [`anyhow::Error`], discarding the
+    /// value.
+    /// 
+    /// The message of the `anyhow::Error` lists every error, and its source is the first error.
+    /// 
+    /// Requires the `anyhow` feature.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// fn parse_all(input: &[&str]) -> Outcome<Vec<u32>, std::num::ParseIntError> {
+    ///     input.iter().map(|s| Outcome::from_result_or(s.parse(), 0)).collect()
+    /// }
+    /// 
+    /// assert_eq!(parse_all(&["1", "2"]).into_anyhow().unwrap(), vec![1, 2]);
+    /// 
+    /// let error = parse_all(&["1", "x", ""]).into_anyhow().unwrap_err();
+    /// let message = error.to_string();
+    /// assert!(message.contains("invalid digit found in string"));
+    /// assert!(message.contains("cannot parse integer from empty string"));
+    /// assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+    /// ```
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow(self) -> anyhow::Result<T>
+    where
+        E: core::error::Error + Send + Sync + 'static,
+    {
+        self.into_result_error().map_err(anyhow::Error::new)
+    }
+
+    /// Converts this `Outcome` into a [`Result`] with a [`MultiDiagnostic`], which implements
+    /// [`miette::Diagnostic`]:
+    /// 
+    /// - If there are no errors, produces an [`Ok`] with the value.
+    /// - Otherwise, produces an [`Err`] with the errors, discarding the value.
+    /// 
+    /// Requires the `miette` feature.
+    /// 
+    /// [`MultiDiagnostic`]: crate::MultiDiagnostic
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// use miette::{Diagnostic, MietteDiagnostic};
+    /// 
+    /// let o = Outcome::new_with_errors(42, vec![
+    ///     MietteDiagnostic::new("error 1"),
+    ///     MietteDiagnostic::new("error 2"),
+    /// ]);
+    /// let error = o.into_miette().unwrap_err();
+    /// 
+    /// let related: Vec<_> = error.related().unwrap().map(|d| d.to_string()).collect();
+    /// assert_eq!(related, vec!["error 1", "error 2"]);
+    /// ```
+    #[cfg(feature = "miette")]
+    pub fn into_miette(self) -> Result<T, crate::MultiDiagnostic<E>>
+    where
+        E: miette::Diagnostic,
+    {
+        self.into_result_vec().map_err(crate::MultiDiagnostic)
+    }
+
+    /// Converts this `Outcome` into an [`ErrorSentinel`], discarding the value.
+    /// 
+    /// You **must** handle the errors before they are dropped, as with [`finalize`].
+    /// 
+    /// [`finalize`]: Outcome::finalize
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn into_errors(self) -> ErrorSentinel<E> {
+        ErrorSentinel::new(self.errors)
+    }
+
+    /// Consumes this `Outcome`, handling its errors by emitting a [`log`] record for each one at the
+    /// given level, and returns its value.
+    /// 
+    /// This is a shorthand for [`finalize`] followed by [`ErrorSentinel::handle_with_log`], using
+    /// `ocm` as the target.
+    /// 
+    /// Requires the `log` feature.
+    /// 
+    /// [`finalize`]: Outcome::finalize
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// # use std::sync::Mutex;
+    /// # use log::{Level, Log, Metadata, Record};
+    /// # static RECORDS: Mutex<Vec<String>> = Mutex::new(vec![]);
+    /// # struct Capture;
+    /// # impl Log for Capture {
+    /// #     fn enabled(&self, _: &Metadata) -> bool { true }
+    /// #     fn log(&self, record: &Record) {
+    /// #         RECORDS.lock().unwrap().push(record.args().to_string());
+    /// #     }
+    /// #     fn flush(&self) {}
+    /// # }
+    /// # log::set_logger(&Capture).unwrap();
+    /// # log::set_max_level(log::LevelFilter::Trace);
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// assert_eq!(o.finalize_logging(Level::Error), 42);
+    /// # assert_eq!(*RECORDS.lock().unwrap(), vec!["error 1", "error 2"]);
+    /// 
+    /// // Nothing is logged if there are no errors
+    /// let o: Outcome<_, &str> = Outcome::new(42);
+    /// assert_eq!(o.finalize_logging(Level::Error), 42);
+    /// # assert_eq!(RECORDS.lock().unwrap().len(), 2);
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn finalize_logging(self, level: log::Level) -> T
+    where
+        E: core::fmt::Display,
+    {
+        let (value, errors) = self.finalize();
+        errors.handle_with_log(level, "ocm");
+        value
+    }
+
+    /// Consumes this `Outcome`, handling its errors by printing each one on its own line to standard
+    /// error. Returns the value and the number of errors.
+    /// 
+    /// This is a shorthand for [`finalize`] followed by [`ErrorSentinel::handle_eprint`].
+    /// 
+    /// [`finalize`]: Outcome::finalize
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// assert_eq!(o.finalize_eprint(), (42, 2));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn finalize_eprint(self) -> (T, usize)
+    where
+        E: core::fmt::Display,
+    {
+        let (value, errors) = self.finalize();
+        (value, errors.handle_eprint())
+    }
+
+    /// Consumes this `Outcome`, returning its value if there are no errors. Otherwise, prints the
+    /// errors and exits the process with the given code.
+    /// 
+    /// This is a shorthand for [`finalize`] followed by [`ErrorSentinel::exit_if_errors`].
+    /// 
+    /// [`finalize`]: Outcome::finalize
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o: Outcome<_, &str> = Outcome::new(42);
+    /// assert_eq!(o.finalize_or_exit(1), 42);
+    /// ```
+    /// 
+    /// ```no_run
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1"]);
+    /// o.finalize_or_exit(1); // Exits the process
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn finalize_or_exit(self, code: u8) -> T
+    where
+        E: core::fmt::Display,
+    {
+        let (value, errors) = self.finalize();
+        errors.exit_if_errors(code);
+        value
+    }
+
+    /// Consumes this `Outcome`, passing its errors to `f` if there are any, and then returning its
+    /// value. The errors are considered handled either way.
+    /// 
+    /// This is a middle ground between [`unwrap`], which panics if there are errors, and
+    /// [`ErrorSentinel::ignore`], which discards them silently - it's useful for best-effort
+    /// operations where problems should be reported, but shouldn't stop the program.
+    /// 
+    /// [`unwrap`]: Outcome::unwrap
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut warnings = vec![];
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let value = o.warn_if_errors(|errors| warnings.push(format!("{} problems", errors.len())));
+    /// assert_eq!(value, 42);
+    /// assert_eq!(warnings, vec!["2 problems"]);
+    /// 
+    /// // `f` is not called if there are no errors
+    /// let o: Outcome<_, &str> = Outcome::new(42);
+    /// let value = o.warn_if_errors(|_| panic!("no errors expected"));
+    /// assert_eq!(value, 42);
+    /// ```
+    pub fn warn_if_errors(self, f: impl FnOnce(&[E])) -> T {
+        if self.has_errors() {
+            f(&self.errors);
+        }
+        self.value
+    }
+
+    /// Consumes this `Outcome`, splitting it into an `Outcome` with the same value but using
+    /// [`Infallible`] as its error type, and a plain [`Vec`] of the errors.
+    /// 
+    /// The new `Outcome` is statically guaranteed to have no errors, so it can be passed to code
+    /// which requires a clean value. Like [`into_parts`], the errors are not protected by an
+    /// [`ErrorSentinel`].
+    /// 
+    /// [`into_parts`]: Outcome::into_parts
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let (clean, errors) = o.split();
+    /// assert_eq!(errors, vec!["error 1", "error 2"]);
+    /// 
+    /// // No errors are possible, so they can be safely ignored
+    /// let (value, no_errors) = clean.finalize();
+    /// no_errors.safely_ignore();
+    /// assert_eq!(value, 42);
+    /// ```
+    #[must_use]
+    pub fn split(self) -> (Outcome<T, Infallible>, Vec<E>) {
+        (Outcome::new(self.value), self.errors)
+    }
+
+    /// Iterates over mutable references to the errors within this `Outcome`, allowing them to be
+    /// edited in-place.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(42, vec!["error 1".to_owned(), "error 2".to_owned()]);
+    /// for err in o.iter_errors_mut() {
+    ///     err.insert_str(0, "main.rs: ");
+    /// }
+    /// 
+    /// let (_, errors) = o.finalize();
+    /// assert_eq!(errors.peek(), &["main.rs: error 1", "main.rs: error 2"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn iter_errors_mut(&mut self) -> core::slice::IterMut<'_, E> {
+        self.errors.iter_mut()
+    }
+
+    /// Returns a mutable reference to the underlying [`Vec`] of errors, allowing any manipulation
+    /// which `Vec` supports, such as reordering, inserting at a position, or truncating.
+    /// 
+    /// For common cases, prefer more targeted methods like [`retain_errors`] or
+    /// [`map_errors_in_place`].
+    /// 
+    /// [`retain_errors`]: Outcome::retain_errors
+    /// [`map_errors_in_place`]: Outcome::map_errors_in_place
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(42, vec!["error 3", "error 1", "error 2", "error 4"]);
+    /// o.errors_mut().sort();
+    /// o.errors_mut().truncate(2);
+    /// o.errors_mut().insert(0, "summary");
+    /// 
+    /// let (_, errors) = o.finalize();
+    /// assert_eq!(errors.peek(), &["summary", "error 1", "error 2"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn errors_mut(&mut self) -> &mut Vec<E> {
+        &mut self.errors
+    }
+
+    /// Removes all of the errors from this `Outcome`, returning them as a lazy iterator, and keeping
+    /// the value.
+    /// 
+    /// This has the same semantics as [`Vec::drain`]: when the iterator is dropped, every error is
+    /// removed, even if the iterator was only partially consumed. The errors which weren't iterated
+    /// over are dropped, so stopping early discards them.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new_with_errors(42, vec!["error 1", "error 2", "fatal", "error 3"]);
+    /// 
+    /// let mut reported = vec![];
+    /// for error in o.drain_errors() {
+    ///     reported.push(error);
+    ///     if error == "fatal" {
+    ///         break;
+    ///     }
+    /// }
+    /// 
+    /// assert_eq!(reported, vec!["error 1", "error 2", "fatal"]);
+    /// assert!(o.is_success());
+    /// assert_eq!(o.unwrap(), 42);
+    /// ```
+    pub fn drain_errors(&mut self) -> vec::Drain<'_, E> {
+        self.errors.drain(..)
+    }
+
+    /// Shrinks the capacity of the underlying [`Vec`] of errors as much as possible.
+    /// 
+    /// After pushing many errors and then removing most of them, the `Vec` may hold on to far more
+    /// memory than it needs. This reclaims it, which is worthwhile for long-lived outcomes, such as
+    /// those stored in a cache.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, Outcome};
+    /// let mut o = Outcome::build(|errs| {
+    ///     for i in 0..1000 {
+    ///         errs.push_error(i);
+    ///     }
+    ///     errs.peek_mut().retain(|i| i % 100 == 0);
+    /// });
+    /// o.shrink_to_fit();
+    /// 
+    /// let ((), mut errors) = o.finalize();
+    /// assert_eq!(errors.len(), 10);
+    /// assert!(errors.peek_mut().capacity() < 1000);
+    /// # errors.ignore();
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.errors.shrink_to_fit();
+    }
+
+    /// Computes summary statistics about the errors within this `Outcome`, without consuming it.
+    /// See [`ErrorStats`] for an example.
+    #[must_use]
+    pub fn error_stats(&self) -> ErrorStats<'_, E> {
+        ErrorStats::new(&self.errors)
+    }
+
+    /// Compares this `Outcome` with another, ignoring the order of the errors.
+    /// 
+    /// The values must be equal, and the errors must be equal as a multiset: each error must
+    /// appear the same number of times in both, but in any order. This is useful when errors are
+    /// produced in a nondeterministic order, such as by concurrent tasks.
+    /// 
+    /// The derived [`PartialEq`] implementation remains strict about the order of errors. To use an
+    /// `Outcome` as a map key with the same semantics, wrap it in [`UnorderedErrors`].
+    /// 
+    /// [`UnorderedErrors`]: crate::UnorderedErrors
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let a = Outcome::new_with_errors(42, vec!["error 1", "error 2", "error 1"]);
+    /// let b = Outcome::new_with_errors(42, vec!["error 2", "error 1", "error 1"]);
+    /// assert!(a.eq_unordered(&b));
+    /// assert_ne!(a, b);
+    /// 
+    /// let c = Outcome::new_with_errors(42, vec!["error 2", "error 2", "error 1"]);
+    /// assert!(!a.eq_unordered(&c));
+    /// ```
+    #[must_use]
+    pub fn eq_unordered(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+        E: PartialEq,
+    {
+        if self.value != other.value || self.errors.len() != other.errors.len() {
+            return false;
+        }
+
+        // Match each of our errors against a distinct error in `other`
+        let mut matched = vec![false; other.errors.len()];
+        self.errors.iter().all(|error| {
+            let found = other.errors.iter()
+                .enumerate()
+                .position(|(i, other_error)| !matched[i] && error == other_error);
+
+            match found {
+                Some(i) => {
+                    matched[i] = true;
+                    true
+                }
+                None => false,
+            }
+        })
+    }
+
+    /// Compares this `Outcome` with another by the number of errors each contains. An `Outcome`
+    /// with fewer errors is considered [`Less`](Ordering::Less).
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// # use std::cmp::Ordering;
+    /// let mut candidates = vec![
+    ///     Outcome::new_with_errors("a", vec!["error 1", "error 2"]),
+    ///     Outcome::new("b"),
+    ///     Outcome::new_with_errors("c", vec!["error 3"]),
+    /// ];
+    /// candidates.sort_by(Outcome::cmp_by_error_count);
+    /// 
+    /// let order = candidates.iter().map(|o| *o.value()).collect::<Vec<_>>();
+    /// assert_eq!(order, vec!["b", "c", "a"]);
+    /// ```
+    #[must_use]
+    pub fn cmp_by_error_count(&self, other: &Self) -> Ordering {
+        self.len_errors().cmp(&other.len_errors())
+    }
+
+    /// Returns whichever of this `Outcome` and another has fewer errors, discarding the other one.
+    /// If both have the same number of errors, returns `self`.
+    /// 
+    /// This is useful for picking the best of several candidate results, such as different
+    /// strategies for parsing the same input.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let a = Outcome::new_with_errors("a", vec!["error 1", "error 2"]);
+    /// let b = Outcome::new_with_errors("b", vec!["error 3"]);
+    /// assert_eq!(*a.fewer_errors(b).value(), "b");
+    /// 
+    /// // Ties go to `self`
+    /// let a = Outcome::new_with_errors("a", vec!["error 1"]);
+    /// let b = Outcome::new_with_errors("b", vec!["error 2"]);
+    /// assert_eq!(*a.fewer_errors(b).value(), "a");
+    /// ```
+    #[must_use]
+    pub fn fewer_errors(self, other: Self) -> Self {
+        if other.cmp_by_error_count(&self) == Ordering::Less {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Calls a function with a reference to this `Outcome`, and then returns it unchanged.
+    /// 
+    /// This is useful for side effects like logging or metrics in the middle of a chain of
+    /// combinators, since the function can see both the value and the errors.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(vec![1, 2, 3], vec!["oh no!"])
+    ///     .tap(|o| println!("parsed {:?} with {} error(s)", o.value(), o.len_errors()))
+    ///     .map(|nums| nums.iter().sum::<u32>())
+    ///     .tap(|o| println!("summed to {} with {} error(s)", o.value(), o.len_errors()));
+    /// 
+    /// assert_eq!(o.value(), &6);
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn tap(self, func: impl FnOnce(&Self)) -> Self {
+        func(&self);
+        self
+    }
+}
+
+impl<T, E, C: ErrorContainer<E>> Outcome<T, E, C> {
+    /// Constructs a new `Outcome` with a value and an existing container of errors.
+    /// 
+    /// This is the way to create an `Outcome` which stores its errors in a container other than a
+    /// [`Vec`]. See [`ErrorContainer`] for an example.
+    #[must_use]
+    pub fn with_container(value: T, errors: C) -> Self {
+        Self { value, errors, _error: PhantomData }
+    }
+
+    /// Adds a new error to this `Outcome`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new(42);
+    /// o.push_error("oh no!");
+    /// 
+    /// assert!(o.has_errors());
+    /// ```
+    pub fn push_error(&mut self, error: E) {
+        self.errors.push(error);
+    }
+
+    /// Inspect the value within this `Outcome`, without consuming it.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["oh no!"]);
+    /// assert_eq!(o.value(), &42);
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The number of errors within this `Outcome`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new(42);
+    /// o.push_error("this went wrong");
+    /// o.push_error("that went wrong");
+    /// 
+    /// assert_eq!(o.len_errors(), 2);
+    /// ```
+    #[must_use]
+    pub fn len_errors(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if this `Outcome` has any errors.
+    /// 
+    /// Opposite of [`is_success`](#method.is_success).
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.len_errors() > 0
+    }
+
+    /// Returns `true` if this `Outcome` has no errors.
+    /// 
+    /// Opposite of [`has_errors`](#method.has_errors).
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.len_errors() == 0
+    }
+
+    /// Iterates over references to the errors within this `Outcome`, without consuming it.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let lengths: Vec<_> = o.iter_errors().map(|err| err.len()).collect();
+    /// assert_eq!(lengths, vec![7, 7]);
+    /// # o.finalize().1.ignore();
+    /// ```
+    pub fn iter_errors(&self) -> C::Iter<'_> {
+        self.errors.iter()
+    }
+
+    /// Consumes and deconstructs this `Outcome` into its value and a plain [`Vec`] of errors. This
+    /// is the inverse of [`from_parts`].
+    /// 
+    /// This is an escape hatch which bypasses the guarantee provided by [`ErrorSentinel`], so
+    /// nothing checks that the errors are handled. Prefer [`finalize`] unless you specifically need
+    /// the raw components, such as for testing or FFI.
+    /// 
+    /// [`from_parts`]: Outcome::from_parts
+    /// [`finalize`]: Outcome::finalize
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+    /// let (value, errors) = o.into_parts();
+    /// 
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors, vec!["error 1", "error 2"]);
+    /// // No need to handle `errors`
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (T, C) {
+        (self.value, self.errors)
+    }
+
+    /// Consumes and deconstructs this `Outcome` into its value and an [`ErrorSentinel`].
+    /// 
+    /// The `ErrorSentinel` verifies that any errors are handled before it is dropped, most likely
+    /// by calling [`handle`]. Failure to do this will cause a panic, even if there were no errors.
+    /// See the [`ErrorSentinel`] docs for more details.
+    /// 
+    /// [`handle`]: ErrorSentinel::handle
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut o = Outcome::new(42);
+    /// o.push_error("this went wrong");
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, 42);
+    /// 
+    /// errors.handle(|errs| {
+    ///     for err in &errs {
+    ///         println!("error: {err}");
+    ///     }
+    ///     assert_eq!(errs.len(), 1);
+    /// });
+    /// ```
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn finalize(self) -> (T, ErrorSentinel<E>) {
+        (self.value, ErrorSentinel::new(self.errors.into_iter().collect()))
+    }
+
+    /// Moves the errors from this `Outcome` into an [`ErrorCollector`], and unwraps it to return
+    /// its value.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let mut source = Outcome::new(42);
+    /// source.push_error("oh no!");
+    /// source.push_error("another error!");
+    /// let mut dest = Outcome::new(123);
+    /// dest.push_error("one last failure!");
+    /// 
+    /// let source_value = source.propagate(&mut dest);
+    /// assert_eq!(dest.len_errors(), 3);
+    /// assert_eq!(source_value, 42);
+    /// ```
+    #[must_use = "propagate returns the inner value; use `integrate` if you wish to merge values in-place"]
+    pub fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> T {
+        for error in self.errors.into_iter() {
+            other.push_error(error);
+        }
 
-    /// Consumes and deconstructs this `Outcome` into its value and an [`ErrorSentinel`].
+        self.value
+    }
+
+    /// Applies a function to the value within this `Outcome`.
     /// 
-    /// The `ErrorSentinel` verifies that any errors are handled before it is dropped, most likely
-    /// by calling [`handle`]. Failure to do this will cause a panic, even if there were no errors.
-    /// See the [`ErrorSentinel`] docs for more details.
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors("Hello".to_owned(), vec!["oh no!"]);
+    /// let o_rev = o.map(|s| s.len());
     /// 
-    /// [`handle`]: ErrorSentinel::handle
+    /// let (value, errors) = o_rev.finalize();
+    /// assert_eq!(value, 5);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn map<R>(self, func: impl FnOnce(T) -> R) -> Outcome<R, E, C> {
+        Outcome {
+            value: func(self.value),
+            errors: self.errors,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<E> Outcome<(), E> {
+    /// Constructs a new `Outcome` with no meaningful value and no errors. A shorthand for
+    /// `Outcome::new(())`.
     /// 
     /// ```
     /// # use ocm::Outcome;
-    /// let mut o = Outcome::new(42);
-    /// o.push_error("this went wrong");
-    ///
-    /// let (value, errors) = o.finalize();
-    /// assert_eq!(value, 42);
+    /// let mut o = Outcome::unit();
+    /// assert!(o.is_success());
     /// 
-    /// errors.handle(|errs| {
-    ///     for err in &errs {
-    ///         println!("error: {err}");
+    /// o.push_error("oh no!");
+    /// assert_eq!(o.len_errors(), 1);
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn unit() -> Self {
+        Self::new(())
+    }
+
+    /// Constructs a new `Outcome` with no meaningful value, and the given errors. A shorthand for
+    /// `Outcome::new_with_errors((), errors)`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// fn lint(source: &str) -> Outcome<(), String> {
+    ///     Outcome::errors_only(
+    ///         source.lines()
+    ///             .filter(|line| line.len() > 10)
+    ///             .map(|line| format!("line too long: {line}"))
+    ///             .collect(),
+    ///     )
+    /// }
+    /// 
+    /// let ((), errors) = lint("short\nthis is far too long").finalize();
+    /// assert_eq!(errors.peek(), &["line too long: this is far too long"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn errors_only(errors: Vec<E>) -> Self {
+        Self::new_with_errors((), errors)
+    }
+
+    /// Constructs a new `Outcome` with no meaningful value, by running a closure which only
+    /// accumulates errors. A shorthand for [`build_default`] when the value type is `()`.
+    /// 
+    /// [`build_default`]: Outcome::build_default
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// struct Config {
+    ///     name: String,
+    ///     threads: usize,
+    /// }
+    /// 
+    /// fn validate(config: &Config) -> Outcome<(), String> {
+    ///     Outcome::check(|errs| {
+    ///         if config.name.is_empty() {
+    ///             errs.push_error("name must not be empty".to_owned());
+    ///         }
+    ///         if config.threads == 0 {
+    ///             errs.push_error("must use at least one thread".to_owned());
+    ///         }
+    ///     })
+    /// }
+    /// 
+    /// let valid = Config { name: "server".to_owned(), threads: 4 };
+    /// assert!(validate(&valid).is_success());
+    /// 
+    /// let invalid = Config { name: "".to_owned(), threads: 0 };
+    /// assert_eq!(validate(&invalid).len_errors(), 2);
+    /// ```
+    /// 
+    /// Combined with the [`FromIterator`] implementation, this makes it easy to validate many
+    /// things at once:
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// fn validate_port(port: u32) -> Outcome<(), String> {
+    ///     Outcome::check(|errs| {
+    ///         if port > 65535 {
+    ///             errs.push_error(format!("port {port} is out of range"));
+    ///         }
+    ///     })
+    /// }
+    /// 
+    /// let all: Outcome<(), _> = [80, 443, 70000, 99999].into_iter().map(validate_port).collect();
+    /// assert_eq!(all.len_errors(), 2);
+    /// ```
+    #[must_use]
+    pub fn check<F>(func: F) -> Self
+    where
+        F: FnOnce(&mut ErrorSentinel<E>),
+    {
+        Self::build_default(func)
+    }
+}
+
+impl<R, E> Outcome<Vec<R>, E> {
+    /// Constructs a new `Outcome` by calling a closure on each item of an iterator, threading some
+    /// mutable state through each call, and collecting the results into a [`Vec`].
+    /// 
+    /// The closure also receives an [`ErrorCollector`] to push any errors into. This is like a
+    /// combination of [`build`] and [`Iterator::scan`], but never stops early.
+    /// 
+    /// [`build`]: Outcome::build
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// // Find the nesting depth at each token, reporting unbalanced brackets
+    /// let o = Outcome::scan("(a(b))c)".chars(), 0usize, |depth, c, errs| {
+    ///     match c {
+    ///         '(' => *depth += 1,
+    ///         ')' => match depth.checked_sub(1) {
+    ///             Some(new_depth) => *depth = new_depth,
+    ///             None => errs.push_error("unmatched ')'".to_owned()),
+    ///         },
+    ///         _ => (),
     ///     }
-    ///     assert_eq!(errs.len(), 1);
+    ///     *depth
     /// });
+    /// 
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, vec![1, 1, 2, 2, 1, 0, 0, 0]);
+    /// assert_eq!(errors.peek(), &["unmatched ')'"]);
+    /// # errors.ignore();
     /// ```
-    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
-    pub fn finalize(self) -> (T, ErrorSentinel<E>) {
-        (self.value, ErrorSentinel::new(self.errors))
+    #[must_use]
+    pub fn scan<I, S>(iter: I, init: S, mut func: impl FnMut(&mut S, I::Item, &mut dyn ErrorCollector<E>) -> R) -> Self
+    where
+        I: IntoIterator,
+    {
+        let mut state = init;
+        Outcome::build(|errs| {
+            iter.into_iter()
+                .map(|item| func(&mut state, item, errs))
+                .collect()
+        })
+    }
+
+    /// Combines the values of two `Outcome`s containing [`Vec`]s into a [`Vec`] of pairs, padding
+    /// the shorter one with [`None`], and combines their errors.
+    /// 
+    /// The errors of this `Outcome` come before the errors of `other`.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let old = Outcome::new_with_errors(vec!["a", "b", "c"], vec!["old error"]);
+    /// let new = Outcome::new_with_errors(vec![1, 2], vec!["new error"]);
+    /// 
+    /// let (value, errors) = old.zip_longest(new).finalize();
+    /// assert_eq!(value, vec![
+    ///     (Some("a"), Some(1)),
+    ///     (Some("b"), Some(2)),
+    ///     (Some("c"), None),
+    /// ]);
+    /// assert_eq!(errors.peek(), &["old error", "new error"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn zip_longest<OR>(self, other: Outcome<Vec<OR>, E>) -> Outcome<Vec<(Option<R>, Option<OR>)>, E> {
+        self.zip(other).map(|(left, right)| {
+            let len = left.len().max(right.len());
+            let mut left = left.into_iter();
+            let mut right = right.into_iter();
+            (0..len).map(|_| (left.next(), right.next())).collect()
+        })
+    }
+}
+
+impl<T, E> Outcome<Vec<Outcome<T, E>>, E> {
+    /// Flattens an `Outcome` containing a [`Vec`] of `Outcome`s into a single `Outcome` with a
+    /// [`Vec`] of their values.
+    /// 
+    /// The errors of the outer `Outcome` come first, followed by the errors of each inner `Outcome`
+    /// in order.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(
+    ///     vec![
+    ///         Outcome::new_with_errors(1, vec!["inner 1"]),
+    ///         Outcome::new(2),
+    ///         Outcome::new_with_errors(3, vec!["inner 3a", "inner 3b"]),
+    ///     ],
+    ///     vec!["outer"],
+    /// );
+    /// 
+    /// let (value, errors) = o.flatten_vec().finalize();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(errors.peek(), &["outer", "inner 1", "inner 3a", "inner 3b"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn flatten_vec(self) -> Outcome<Vec<T>, E> {
+        let mut errors = self.errors;
+        let values = self.value.into_iter()
+            .map(|inner| inner.propagate(&mut errors))
+            .collect();
+
+        Outcome::new_with_errors(values, errors)
+    }
+}
+
+impl<T: Clone, E> Outcome<&T, E> {
+    /// Maps an `Outcome<&T, E>` to an `Outcome<T, E>` by cloning the value. The errors are left
+    /// untouched.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(vec![1, 2, 3], vec!["oh no!"]);
+    /// let cloned: Outcome<Vec<u32>, &&str> = o.as_ref().cloned();
+    /// 
+    /// let (value, errors) = cloned.finalize();
+    /// assert_eq!(value, vec![1, 2, 3]);
+    /// assert_eq!(errors.peek(), &[&"oh no!"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn cloned(self) -> Outcome<T, E> {
+        self.map(T::clone)
     }
 }
 
-impl<T, E> ErrorCollector<E> for Outcome<T, E> {
+impl<T: Copy, E> Outcome<&T, E> {
+    /// Maps an `Outcome<&T, E>` to an `Outcome<T, E>` by copying the value. The errors are left
+    /// untouched.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(42, vec!["oh no!"]);
+    /// let copied: Outcome<u32, &&str> = o.as_ref().copied();
+    /// 
+    /// let (value, errors) = copied.finalize();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(errors.len(), 1);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn copied(self) -> Outcome<T, E> {
+        self.map(|value| *value)
+    }
+}
+
+impl<T, E: Severity> Outcome<T, E> {
+    /// Returns `true` if any of the errors in this `Outcome` are fatal, according to their
+    /// [`Severity`] implementation.
+    /// 
+    /// This is different from [`has_errors`], which also considers warnings. An `Outcome` with only
+    /// warnings is neither fatal nor a success.
+    /// 
+    /// [`has_errors`]: Outcome::has_errors
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, Severity};
+    /// struct Diagnostic { fatal: bool }
+    /// impl Severity for Diagnostic {
+    ///     fn is_fatal(&self) -> bool { self.fatal }
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors((), vec![Diagnostic { fatal: false }]);
+    /// assert!(!o.is_fatal());
+    /// assert!(!o.is_success());
+    /// # o.finalize().1.ignore();
+    /// 
+    /// let o = Outcome::new_with_errors((), vec![Diagnostic { fatal: false }, Diagnostic { fatal: true }]);
+    /// assert!(o.is_fatal());
+    /// # o.finalize().1.ignore();
+    /// ```
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        self.errors.iter().any(Severity::is_fatal)
+    }
+
+    /// Returns `true` if none of the errors in this `Outcome` are fatal, so it only has warnings or
+    /// nothing at all.
+    /// 
+    /// Opposite of [`is_fatal`](#method.is_fatal). Unlike [`is_success`], warnings are not
+    /// considered.
+    /// 
+    /// [`is_success`]: Outcome::is_success
+    #[must_use]
+    pub fn is_success_ignoring_warnings(&self) -> bool {
+        !self.is_fatal()
+    }
+
+    /// Iterates over the errors in this `Outcome` which are fatal.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, Severity};
+    /// struct Diagnostic(&'static str, bool);
+    /// impl Severity for Diagnostic {
+    ///     fn is_fatal(&self) -> bool { self.1 }
+    /// }
+    /// 
+    /// let o = Outcome::new_with_errors((), vec![
+    ///     Diagnostic("unused variable", false),
+    ///     Diagnostic("missing semicolon", true),
+    ///     Diagnostic("unreachable code", false),
+    /// ]);
+    /// 
+    /// let fatal: Vec<_> = o.fatal_errors().map(|d| d.0).collect();
+    /// assert_eq!(fatal, vec!["missing semicolon"]);
+    /// 
+    /// let warnings: Vec<_> = o.warnings().map(|d| d.0).collect();
+    /// assert_eq!(warnings, vec!["unused variable", "unreachable code"]);
+    /// # o.finalize().1.ignore();
+    /// ```
+    pub fn fatal_errors(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter().filter(|error| error.is_fatal())
+    }
+
+    /// Iterates over the errors in this `Outcome` which are not fatal. See [`fatal_errors`] for an
+    /// example.
+    /// 
+    /// [`fatal_errors`]: Outcome::fatal_errors
+    pub fn warnings(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter().filter(|error| !error.is_fatal())
+    }
+}
+
+impl<T: Debug, E, C: Debug> Debug for Outcome<T, E, C> {
+    /// Formats the value and the errors, like a derived implementation would.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let o = Outcome::new_with_errors(1, vec!["e"]);
+    /// assert_eq!(format!("{o:?}"), r#"Outcome { value: 1, errors: ["e"] }"#);
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Outcome")
+            .field("value", &self.value)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl<T, E, C: ErrorContainer<E>> ErrorCollector<E> for Outcome<T, E, C> {
     type WrappedInner = T;
 
     fn push_error(&mut self, error: E) {
         Outcome::push_error(self, error);
     }
 
-    fn propagate(self, other: &mut impl ErrorCollector<E>) -> Self::WrappedInner {
+    fn errors(&self) -> Option<&[E]> {
+        self.errors.as_slice()
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.errors.iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
         Outcome::propagate(self, other)
     }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in core::mem::take(&mut self.errors) {
+            other.push_error(error);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Display> Termination for Outcome<(), E> {
+    /// Allows an `Outcome` to be returned from `main`. If there are any errors, they are reported
+    /// in the same way as [`ErrorSentinel::handle_exit`], and the process exits with a failure
+    /// code.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, ErrorCollector};
+    /// fn main() -> Outcome<(), String> {
+    ///     Outcome::check(|errs| {
+    ///         for arg in std::env::args().skip(1) {
+    ///             if arg.is_empty() {
+    ///                 errs.push_error("empty argument".to_owned());
+    ///             }
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    fn report(self) -> ExitCode {
+        let ((), errors) = self.into_parts();
+        if errors.is_empty() {
+            return ExitCode::SUCCESS;
+        }
+
+        eprint!("{}", crate::report::exit_report(&errors));
+        ExitCode::FAILURE
+    }
+}
+
+impl<T, E> From<Outcome<T, E>> for Result<T, Vec<E>> {
+    /// Converts an `Outcome` into a [`Result`] with a plain [`Vec`] of errors. See
+    /// [`Outcome::into_result_vec`].
+    fn from(outcome: Outcome<T, E>) -> Self {
+        outcome.into_result_vec()
+    }
+}
+
+impl<T, E> From<Result<T, Vec<E>>> for Outcome<Option<T>, E> {
+    /// Converts a [`Result`] with a [`Vec`] of errors into an `Outcome`.
+    /// 
+    /// A `Result` cannot contain a value alongside its errors, so the value of the `Outcome` is an
+    /// [`Option`]: `Some` for an `Ok`, and `None` for an `Err`. This avoids having to invent a
+    /// value when there are errors.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let ok: Result<u32, Vec<&str>> = Ok(42);
+    /// let o: Outcome<_, _> = ok.into();
+    /// assert_eq!(o.unwrap(), Some(42));
+    /// 
+    /// let err: Result<u32, Vec<&str>> = Err(vec!["error 1", "error 2"]);
+    /// let o: Outcome<_, _> = err.into();
+    /// let (value, errors) = o.finalize();
+    /// assert_eq!(value, None);
+    /// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+    /// # errors.ignore();
+    /// ```
+    /// 
+    /// Converting back again recovers the original `Result`, as long as any `Err` has at least one
+    /// error:
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// for original in [Ok(42), Err(vec!["error 1", "error 2"])] {
+    ///     let o: Outcome<Option<u32>, _> = original.clone().into();
+    ///     let round_tripped = o.into_result_vec().map(Option::unwrap);
+    ///     assert_eq!(round_tripped, original);
+    /// }
+    /// ```
+    fn from(result: Result<T, Vec<E>>) -> Self {
+        match result {
+            Ok(value) => Outcome::new(Some(value)),
+            Err(errors) => Outcome::new_with_errors(None, errors),
+        }
+    }
 }
 
 impl<T, E, C: FromIterator<T>> FromIterator<Outcome<T, E>> for Outcome<C, E> {
@@ -472,3 +2520,81 @@ impl<T, E, C: FromIterator<T>> FromIterator<Outcome<T, E>> for Outcome<C, E> {
         Outcome::new_with_errors(items.into_iter().collect(), errors)
     }
 }
+
+impl<T: Sum, E> Sum<Outcome<T, E>> for Outcome<T, E> {
+    /// Sums the values of an [`Iterator`] of `Outcome` items into a single `Outcome`.
+    /// 
+    /// The errors are aggregated in order.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let items = vec![
+    ///     Outcome::new_with_errors(1, vec!["error 1"]),
+    ///     Outcome::new(2),
+    ///     Outcome::new_with_errors(3, vec!["error 2", "error 3"]),
+    /// ];
+    /// 
+    /// let (value, errors) = items.into_iter().sum::<Outcome<u32, _>>().finalize();
+    /// assert_eq!(value, 6);
+    /// assert_eq!(errors.peek(), &["error 1", "error 2", "error 3"]);
+    /// # errors.ignore();
+    /// ```
+    fn sum<I: Iterator<Item = Outcome<T, E>>>(iter: I) -> Self {
+        let mut errors = vec![];
+        let value = iter.map(|item| item.propagate(&mut errors)).sum();
+        Outcome::new_with_errors(value, errors)
+    }
+}
+
+impl<T: Product, E> Product<Outcome<T, E>> for Outcome<T, E> {
+    /// Multiplies the values of an [`Iterator`] of `Outcome` items into a single `Outcome`.
+    /// 
+    /// The errors are aggregated in order.
+    /// 
+    /// ```
+    /// # use ocm::Outcome;
+    /// let items = vec![
+    ///     Outcome::new_with_errors(2, vec!["error 1"]),
+    ///     Outcome::new(3),
+    ///     Outcome::new_with_errors(4, vec!["error 2"]),
+    /// ];
+    /// 
+    /// let (value, errors) = items.into_iter().product::<Outcome<u32, _>>().finalize();
+    /// assert_eq!(value, 24);
+    /// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+    /// # errors.ignore();
+    /// ```
+    fn product<I: Iterator<Item = Outcome<T, E>>>(iter: I) -> Self {
+        let mut errors = vec![];
+        let value = iter.map(|item| item.propagate(&mut errors)).product();
+        Outcome::new_with_errors(value, errors)
+    }
+}
+
+/// Owns the sentinel used by [`Outcome::build_async`], so that if its future is cancelled before any
+/// errors were pushed, the empty sentinel can be considered handled rather than panicking.
+struct CancelGuard<E>(Option<ErrorSentinel<E>>);
+
+impl<E> Drop for CancelGuard<E> {
+    fn drop(&mut self) {
+        if let Some(sentinel) = self.0.take() {
+            if sentinel.peek().is_empty() {
+                sentinel.ignore();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::Outcome;
+
+    // Doctests don't check the message of `should_panic(expected = ...)`, but unit tests do
+    #[test]
+    #[should_panic(expected = r#"expected no errors, found 2: ["error 1", "error 2"]"#)]
+    fn assert_empty_message() {
+        Outcome::new_with_errors(42, vec!["error 1", "error 2"]).assert_empty();
+    }
+}
@@ -0,0 +1,56 @@
+use alloc::string::String;
+use core::fmt::{Display, Write};
+
+/// Formats the report printed to standard error when a process exits because of errors: each error
+/// on its own line, followed by a summary line with the number of errors.
+/// 
+/// This is shared by [`ErrorSentinel::handle_exit`](crate::ErrorSentinel::handle_exit), the other
+/// exiting handlers, and the [`Termination`](std::process::Termination) implementation for
+/// [`Outcome`](crate::Outcome), so that they all report errors in the same way.
+pub(crate) fn exit_report<E: Display>(errors: &[E]) -> String {
+    let mut report = String::new();
+    for error in errors {
+        // Writing to a `String` can't fail
+        let _ = writeln!(report, "{error}");
+    }
+    let _ = writeln!(report, "{} error(s) occurred", errors.len());
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::{ExitCode, Termination};
+
+    use crate::Outcome;
+
+    use super::exit_report;
+
+    #[test]
+    fn lists_errors_then_count() {
+        assert_eq!(
+            exit_report(&["error 1", "error 2"]),
+            "error 1\nerror 2\n2 error(s) occurred\n",
+        );
+    }
+
+    #[test]
+    fn single_error() {
+        assert_eq!(exit_report(&["oh no"]), "oh no\n1 error(s) occurred\n");
+    }
+
+    #[test]
+    fn no_errors() {
+        assert_eq!(exit_report::<&str>(&[]), "0 error(s) occurred\n");
+    }
+
+    #[test]
+    fn multiline_errors_are_kept_intact() {
+        assert_eq!(exit_report(&["line 1\nline 2"]), "line 1\nline 2\n1 error(s) occurred\n");
+    }
+
+    #[test]
+    fn termination_exit_code() {
+        assert_eq!(Outcome::<(), &str>::new(()).report(), ExitCode::SUCCESS);
+        assert_eq!(Outcome::new_with_errors((), vec!["error 1"]).report(), ExitCode::FAILURE);
+    }
+}
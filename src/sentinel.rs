@@ -1,11 +1,24 @@
-use std::{fmt::Debug, thread::panicking};
+use core::{convert::Infallible, error::Error, fmt::{Debug, Display}, iter::Peekable};
 
-use crate::{ErrorCollector, Outcome};
+use alloc::{boxed::Box, rc::Rc, string::{String, ToString}, sync::Arc, vec, vec::Vec};
+
+use crate::{ErrorCollector, ErrorStats, MultiError, Outcome, WriteError, hook::{report_unhandled, UnhandledErrors}};
+
+#[cfg(feature = "std")]
+use std::thread::panicking;
+
+/// Without `std`, there is no way to tell whether the thread is panicking, so assume it isn't.
+#[cfg(not(feature = "std"))]
+fn panicking() -> bool {
+    false
+}
 
 /// Represents errors which must be handled before this sentinel is dropped.
 /// 
 /// `ErrorSentinel` has a custom implementation of the [`Drop`] trait which checks that the errors
 /// were handled in some way, and panics if not.
+#[cfg_attr(feature = "std", doc = "(This can be changed by installing a hook with")]
+#[cfg_attr(feature = "std", doc = "[`set_unhandled_hook`](crate::set_unhandled_hook).)")]
 /// 
 /// ```should_panic
 /// # use ocm::ErrorSentinel;
@@ -46,7 +59,18 @@ use crate::{ErrorCollector, Outcome};
 /// an operation, which can then be returned as an [`Outcome`]. See [`into_outcome`] and
 /// [`Outcome::build`].
 /// 
+/// Without the default `std` feature, there is no way to tell whether the thread is already
+/// panicking, so an unhandled `ErrorSentinel` dropped while unwinding from another panic will
+/// panic again, aborting the process. Hooks are also unavailable, so dropping unhandled errors
+/// always panics.
+/// 
+/// `ErrorSentinel` intentionally does not implement `Serialize` or `Deserialize`, even with the
+/// `serde` feature enabled. A sentinel represents an obligation to handle errors within the running
+/// program, which can't meaningfully be written out and read back in. Convert it into an
+/// [`Outcome`] with [`into_outcome`] first if the errors need to be serialized.
+/// 
 /// [`into_outcome`]: ErrorSentinel::into_outcome
+#[derive(Debug)]
 pub struct ErrorSentinel<E> {
     /// The list of errors produced. Wrapped in an [`Option`] to permit moving the errors out of 
     /// `self`.
@@ -56,6 +80,10 @@ pub struct ErrorSentinel<E> {
     /// is still required to indicate to the [`Drop`] implementation that the sentinel was dropped
     /// by being handled properly.
     handled: bool,
+
+    /// A label to identify this sentinel in the message reported if it is dropped unhandled. Set
+    /// with [`label`](ErrorSentinel::label).
+    label: Option<&'static str>,
 }
 
 impl<E> ErrorSentinel<E> {
@@ -69,6 +97,7 @@ impl<E> ErrorSentinel<E> {
         Self {
             errors: Some(errors),
             handled: false,
+            label: None,
         }
     }
 
@@ -77,9 +106,32 @@ impl<E> ErrorSentinel<E> {
         Self {
             errors: Some(vec![]),
             handled: false,
+            label: None,
         }
     }
     
+    /// Attaches a label to this `ErrorSentinel`, which is included in the panic message if it is
+    /// dropped without handling its errors. This makes it easier to track down which sentinel was
+    /// responsible when there are many of them.
+    /// 
+    /// The label is carried over to the iterator created by [`into_errors_iter`].
+    /// 
+    /// [`into_errors_iter`]: ErrorSentinel::into_errors_iter
+    /// 
+    /// ```should_panic
+    /// # use ocm::ErrorSentinel;
+    /// {
+    ///     let errors = ErrorSentinel::new(vec!["error 1", "error 2"]).label("config validation");
+    ///     // Panic occurs here, with the message:
+    ///     //   sentinel 'config validation' dropped without handling errors
+    /// }
+    /// ```
+    #[must_use]
+    pub fn label(mut self, label: &'static str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
     /// Handles the errors by executing a closure, returning the value which it evaluates to.
     /// 
     /// ```
@@ -116,6 +168,293 @@ impl<E> ErrorSentinel<E> {
         handler(self.errors.take().unwrap())
     }
 
+    /// Handles the errors by executing a closure which may itself fail, returning its [`Result`].
+    /// 
+    /// The errors are considered handled whether or not the closure succeeds, so this composes
+    /// with the `?` operator.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// use std::fmt::Write;
+    /// 
+    /// fn report(errors: ErrorSentinel<&str>, out: &mut String) -> std::fmt::Result {
+    ///     errors.try_handle(|errs| {
+    ///         for err in errs {
+    ///             writeln!(out, "error: {err}")?;
+    ///         }
+    ///         Ok(())
+    ///     })?;
+    ///     writeln!(out, "done")
+    /// }
+    /// 
+    /// let mut out = String::new();
+    /// report(ErrorSentinel::new(vec!["error 1", "error 2"]), &mut out).unwrap();
+    /// assert_eq!(out, "error: error 1\nerror: error 2\ndone\n");
+    /// ```
+    /// 
+    /// A failing handler still counts as handling the errors, so this doesn't panic:
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1"]);
+    /// let result: Result<(), &str> = errors.try_handle(|_| Err("report failed"));
+    /// assert_eq!(result, Err("report failed"));
+    /// ```
+    pub fn try_handle<R, HErr>(self, handler: impl FnOnce(Vec<E>) -> Result<R, HErr>) -> Result<R, HErr> {
+        self.handle(handler)
+    }
+
+    /// Handles the errors by printing each one on its own line to standard error, and returns the
+    /// number of errors.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// assert_eq!(errors.handle_eprint(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle_eprint(self) -> usize
+    where
+        E: Display,
+    {
+        self.handle_eprint_with_prefix("")
+    }
+
+    /// Like [`handle_eprint`], but prints a prefix before each error.
+    /// 
+    /// [`handle_eprint`]: ErrorSentinel::handle_eprint
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// assert_eq!(errors.handle_eprint_with_prefix("error: "), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle_eprint_with_prefix(self, prefix: &str) -> usize
+    where
+        E: Display,
+    {
+        self.handle(|errs| {
+            for err in &errs {
+                eprintln!("{prefix}{err}");
+            }
+            errs.len()
+        })
+    }
+
+    /// Handles the errors by printing each one on its own line to standard output, and returns the
+    /// number of errors.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// assert_eq!(errors.handle_println(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle_println(self) -> usize
+    where
+        E: Display,
+    {
+        self.handle(|errs| {
+            for err in &errs {
+                println!("{err}");
+            }
+            errs.len()
+        })
+    }
+
+    /// Handles the errors by printing each one on its own line to standard error, followed by a
+    /// summary line with the number of errors, and then exits the process with the given code.
+    /// 
+    /// This is intended for command-line tools. Because the process exits, destructors on the
+    /// current stack and other threads will not run. See [`std::process::exit`].
+    /// 
+    /// ```no_run
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// 
+    /// // Prints:
+    /// //   error 1
+    /// //   error 2
+    /// //   2 error(s) occurred
+    /// errors.handle_exit(1);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle_exit(self, code: u8) -> !
+    where
+        E: Display,
+    {
+        let report = self.handle(|errs| crate::report::exit_report(&errs));
+        eprint!("{report}");
+        std::process::exit(code.into())
+    }
+
+    /// Like [`handle_exit`], but returns normally if there are no errors, considering the empty
+    /// sentinel handled.
+    /// 
+    /// [`handle_exit`]: ErrorSentinel::handle_exit
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors: ErrorSentinel<&str> = ErrorSentinel::empty();
+    /// errors.exit_if_errors(1);
+    /// println!("still running!");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn exit_if_errors(self, code: u8)
+    where
+        E: Display,
+    {
+        if self.any() {
+            self.handle_exit(code);
+        } else {
+            self.ignore();
+        }
+    }
+
+    /// Handles the errors by writing each one on its own line to an [`io::Write`], and returns the
+    /// number of errors written.
+    /// 
+    /// If a write fails, the errors which have not yet been written, including the one which failed,
+    /// are returned in a new sentinel inside the [`WriteError`]. They must be handled as usual.
+    /// 
+    /// [`io::Write`]: std::io::Write
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// 
+    /// let mut buffer = vec![];
+    /// assert_eq!(errors.handle_write(&mut buffer).unwrap(), 2);
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "error 1\nerror 2\n");
+    /// ```
+    /// 
+    /// If the writer fails partway through:
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2", "error 3"]);
+    /// 
+    /// // Only has room for one error
+    /// let mut buffer = [0u8; 10];
+    /// let error = errors.handle_write(&mut &mut buffer[..]).unwrap_err();
+    /// assert_eq!(error.written(), 1);
+    /// 
+    /// let unwritten = error.into_unwritten();
+    /// assert_eq!(unwritten.peek(), &["error 2", "error 3"]);
+    /// # unwritten.ignore();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle_write(self, writer: &mut impl std::io::Write) -> Result<usize, WriteError<E, std::io::Error>>
+    where
+        E: Display,
+    {
+        self.handle_write_each(|error| writeln!(writer, "{error}"))
+    }
+
+    /// Handles the errors by writing each one on its own line to a [`fmt::Write`], such as a
+    /// [`String`].
+    #[cfg_attr(feature = "std", doc = "This is the `fmt::Write` equivalent of")]
+    #[cfg_attr(feature = "std", doc = "[`handle_write`](ErrorSentinel::handle_write).")]
+    /// 
+    /// [`fmt::Write`]: core::fmt::Write
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// 
+    /// let mut report = String::new();
+    /// assert_eq!(errors.handle_write_fmt(&mut report).unwrap(), 2);
+    /// assert_eq!(report, "error 1\nerror 2\n");
+    /// ```
+    pub fn handle_write_fmt(self, writer: &mut impl core::fmt::Write) -> Result<usize, WriteError<E, core::fmt::Error>>
+    where
+        E: Display,
+    {
+        self.handle_write_each(|error| writeln!(writer, "{error}"))
+    }
+
+    /// Builds a JSON report of the errors, without considering them handled.
+    /// 
+    /// The report is an object with a `count` of the errors, and an `errors` array. If a `tool`
+    /// name is given, it is included as a `tool` field.
+    /// 
+    /// Requires the `json-report` feature.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// let report = errors.to_json_report(Some("linter")).unwrap();
+    /// 
+    /// assert_eq!(report, serde_json::json!({
+    ///     "tool": "linter",
+    ///     "count": 2,
+    ///     "errors": ["error 1", "error 2"],
+    /// }));
+    /// # errors.ignore();
+    /// ```
+    #[cfg(feature = "json-report")]
+    pub fn to_json_report(&self, tool: Option<&str>) -> serde_json::Result<serde_json::Value>
+    where
+        E: serde::Serialize,
+    {
+        serde_json::to_value(crate::json_report::JsonReport::new(tool, self.peek()))
+    }
+
+    /// Handles the errors by writing a JSON report of them to an [`io::Write`]. The report has the
+    /// same format as [`to_json_report`].
+    /// 
+    /// If serializing or writing fails, the errors are returned in a new sentinel inside the
+    /// [`WriteError`], and must be handled as usual.
+    /// 
+    /// Requires the `json-report` feature.
+    /// 
+    /// [`io::Write`]: std::io::Write
+    /// [`to_json_report`]: ErrorSentinel::to_json_report
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1".to_owned(), "error ✗".to_owned()]);
+    /// 
+    /// let mut buffer = vec![];
+    /// errors.handle_json_writer(&mut buffer, None).unwrap();
+    /// 
+    /// let report: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+    /// assert_eq!(report["count"], 2);
+    /// assert_eq!(report["errors"], serde_json::json!(["error 1", "error ✗"]));
+    /// assert!(report.get("tool").is_none());
+    /// ```
+    #[cfg(feature = "json-report")]
+    pub fn handle_json_writer(self, writer: impl std::io::Write, tool: Option<&str>) -> Result<(), WriteError<E, serde_json::Error>>
+    where
+        E: serde::Serialize,
+    {
+        match serde_json::to_writer(writer, &crate::json_report::JsonReport::new(tool, self.peek())) {
+            Ok(()) => {
+                self.ignore();
+                Ok(())
+            }
+            Err(error) => Err(WriteError::new(error, 0, self)),
+        }
+    }
+
+    /// Handles the errors by calling a fallible closure on each one, keeping any errors which were
+    /// not written if it fails.
+    fn handle_write_each<W>(self, mut write: impl FnMut(&E) -> Result<(), W>) -> Result<usize, WriteError<E, W>> {
+        let label = self.label;
+        let mut iter = self.into_errors_iter();
+        let mut written = 0;
+        while let Some(error) = iter.peek() {
+            if let Err(source) = write(error) {
+                let unwritten = ErrorSentinel { errors: Some(iter.collect()), handled: false, label };
+                return Err(WriteError::new(source, written, unwritten));
+            }
+            iter.next();
+            written += 1;
+        }
+        Ok(written)
+    }
+
     /// Handles the errors by moving them into an [`ErrorCollector`], effectively postponing them to
     /// be handled later instead.
     /// 
@@ -128,7 +467,7 @@ impl<E> ErrorSentinel<E> {
     /// assert_eq!(dest.peek().len(), 5);
     /// # dest.ignore();
     /// ```
-    pub fn propagate(self, other: &mut impl ErrorCollector<E>) {
+    pub fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) {
         for error in self.into_errors_iter() {
             other.push_error(error);
         }
@@ -200,6 +539,314 @@ impl<E> ErrorSentinel<E> {
         f
     }
 
+    /// Handles the errors by splitting them into two new sentinels: the first with the errors which
+    /// match a predicate, and the second with those which don't. The relative order of the errors
+    /// is preserved within each.
+    /// 
+    /// Both sentinels must be handled, even if one of them is empty. Any label is carried over to
+    /// both.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["warning: a", "error: b", "warning: c", "error: d"]);
+    /// let (warnings, errors) = errors.split(|e| e.starts_with("warning"));
+    /// 
+    /// assert_eq!(warnings.peek(), &["warning: a", "warning: c"]);
+    /// assert_eq!(errors.peek(), &["error: b", "error: d"]);
+    /// # warnings.ignore();
+    /// # errors.ignore();
+    /// ```
+    /// 
+    /// Handling only one of the sentinels still panics when the other is dropped:
+    /// 
+    /// ```should_panic
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec![1, 2, 3, 4]);
+    /// let (even, odd) = errors.split(|n| n % 2 == 0);
+    /// even.ignore();
+    /// // Panic occurs here, because `odd` is dropped without being handled!
+    /// ```
+    #[must_use = "discarding the `ErrorSentinel`s will panic immediately"]
+    pub fn split(self, pred: impl FnMut(&E) -> bool) -> (ErrorSentinel<E>, ErrorSentinel<E>) {
+        let label = self.label;
+        let (matching, rest): (Vec<_>, Vec<_>) = self.handle(|errors| errors.into_iter().partition(pred));
+
+        let mut matching = ErrorSentinel::new(matching);
+        let mut rest = ErrorSentinel::new(rest);
+        matching.label = label;
+        rest.label = label;
+        (matching, rest)
+    }
+
+    /// Moves the errors into a new sentinel with the same label, applying a function to each.
+    pub(crate) fn map_errors<R>(self, func: impl FnMut(E) -> R) -> ErrorSentinel<R> {
+        let label = self.label;
+        let mut mapped = self.handle(|errors| ErrorSentinel::new(errors.into_iter().map(func).collect()));
+        mapped.label = label;
+        mapped
+    }
+
+    /// Handles the errors by moving them into a [`MultiError`], which implements
+    /// [`Error`](core::error::Error).
+    /// 
+    /// This is useful for returning the errors through an API which expects a single error, such
+    /// as `Box<dyn Error>`. Note that the errors within a `MultiError` are no longer protected by
+    /// a sentinel.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// let error = errors.into_error();
+    /// 
+    /// assert_eq!(error.0, vec!["error 1", "error 2"]);
+    /// assert_eq!(error.to_string(), "2 error(s):\n  - error 1\n  - error 2");
+    /// ```
+    pub fn into_error(self) -> MultiError<E> {
+        self.handle(MultiError)
+    }
+
+    /// Handles the errors by moving them into a reference-counted slice, so that they can be shared
+    /// between several consumers without cloning each error.
+    /// 
+    /// This consumes the sentinel, so counts as handling the errors. The returned [`Rc`] is not
+    /// protected by a sentinel. Use [`into_arc`] if the errors need to be shared across threads.
+    /// 
+    /// [`into_arc`]: ErrorSentinel::into_arc
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1".to_owned(), "error 2".to_owned()]);
+    /// let errors = errors.into_rc();
+    /// 
+    /// let for_report = errors.clone();
+    /// let for_summary = errors.clone();
+    /// assert_eq!(&*for_report, &["error 1", "error 2"]);
+    /// assert_eq!(for_summary.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn into_rc(self) -> Rc<[E]> {
+        self.handle(Rc::from)
+    }
+
+    /// Handles the errors by moving them into an atomically reference-counted slice, so that they
+    /// can be shared between several consumers, including on other threads, without cloning each
+    /// error.
+    /// 
+    /// Like [`into_rc`], this consumes the sentinel, so counts as handling the errors.
+    /// 
+    /// [`into_rc`]: ErrorSentinel::into_rc
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]).into_arc();
+    /// 
+    /// let for_thread = errors.clone();
+    /// let count = std::thread::spawn(move || for_thread.len()).join().unwrap();
+    /// assert_eq!(count, 2);
+    /// assert_eq!(&*errors, &["error 1", "error 2"]);
+    /// ```
+    #[must_use]
+    pub fn into_arc(self) -> Arc<[E]> {
+        self.handle(Arc::from)
+    }
+
+    /// Handles the errors by moving them into a new collector, such as a [`BTreeSet`] or (with the
+    /// `std` feature) a `HashSet`, which discards duplicate errors.
+    /// 
+    /// The errors in the returned collector are not protected by a sentinel.
+    /// 
+    /// [`BTreeSet`]: alloc::collections::BTreeSet
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// # use std::collections::BTreeSet;
+    /// let errors = ErrorSentinel::new(vec!["b", "a", "b"]);
+    /// let set: BTreeSet<_> = errors.into_set();
+    /// assert_eq!(set.into_iter().collect::<Vec<_>>(), vec!["a", "b"]);
+    /// 
+    /// # #[cfg(feature = "std")] {
+    /// # use std::collections::HashSet;
+    /// let errors = ErrorSentinel::new(vec!["b", "a", "b"]);
+    /// let set: HashSet<_> = errors.into_set();
+    /// assert_eq!(set.len(), 2);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_set<S: ErrorCollector<E> + Default>(self) -> S {
+        let mut set = S::default();
+        self.propagate(&mut set);
+        set
+    }
+
+    /// Handles the errors by moving them into a boxed [`MultiError`], for use in functions which
+    /// return a `Box<dyn Error>`, or similar types like `anyhow::Error`.
+    /// 
+    /// If there are no errors, returns [`None`] rather than an empty `MultiError`, so that the
+    /// absence of errors isn't mistaken for a failure. The box can be downcast back to a
+    /// `MultiError<E>`.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorSentinel, MultiError};
+    /// # use std::num::ParseIntError;
+    /// let errors: ErrorSentinel<ParseIntError> = ErrorSentinel::empty();
+    /// assert!(errors.into_boxed_error().is_none());
+    /// 
+    /// let errors = ErrorSentinel::new(vec!["x".parse::<u32>().unwrap_err()]);
+    /// let error = errors.into_boxed_error().unwrap();
+    /// assert_eq!(error.to_string(), "1 error(s):\n  - invalid digit found in string");
+    /// 
+    /// let errors = ErrorSentinel::new(vec![
+    ///     "x".parse::<u32>().unwrap_err(),
+    ///     "".parse::<u32>().unwrap_err(),
+    /// ]);
+    /// let error = errors.into_boxed_error().unwrap();
+    /// let error = error.downcast::<MultiError<ParseIntError>>().unwrap();
+    /// assert_eq!(error.0.len(), 2);
+    /// ```
+    pub fn into_boxed_error(self) -> Option<Box<dyn Error + Send + Sync>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let error = self.into_error();
+        if error.0.is_empty() {
+            None
+        } else {
+            Some(Box::new(error))
+        }
+    }
+
+    /// Handles the errors by moving them into a [`MultiError`], wrapped in an [`anyhow::Error`].
+    /// 
+    /// The message of the `anyhow::Error` lists every error, and its source is the first error.
+    /// Unlike [`into_boxed_error`], this always produces an error, even if there are no errors in
+    /// the sentinel.
+    /// 
+    /// Requires the `anyhow` feature.
+    /// 
+    /// [`into_boxed_error`]: ErrorSentinel::into_boxed_error
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec![
+    ///     "x".parse::<u32>().unwrap_err(),
+    ///     "".parse::<u32>().unwrap_err(),
+    /// ]);
+    /// 
+    /// let error = errors.into_anyhow_error();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "2 error(s):\n  - invalid digit found in string\n  - cannot parse integer from empty string",
+    /// );
+    /// ```
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow_error(self) -> anyhow::Error
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        anyhow::Error::new(self.into_error())
+    }
+
+    /// Handles the errors by moving them into a [`MultiDiagnostic`], wrapped in a
+    /// [`miette::Report`] which renders every error.
+    /// 
+    /// Requires the `miette` feature.
+    /// 
+    /// [`MultiDiagnostic`]: crate::MultiDiagnostic
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// use miette::{MietteDiagnostic, Severity};
+    /// 
+    /// let errors = ErrorSentinel::new(vec![
+    ///     MietteDiagnostic::new("unused variable").with_severity(Severity::Warning),
+    /// ]);
+    /// let report = errors.into_miette_report();
+    /// 
+    /// assert_eq!(report.to_string(), "1 error(s)");
+    /// assert_eq!(report.severity(), Some(Severity::Warning));
+    /// ```
+    #[cfg(feature = "miette")]
+    pub fn into_miette_report(self) -> miette::Report
+    where
+        E: miette::Diagnostic + Send + Sync + 'static,
+    {
+        miette::Report::new(self.handle(crate::MultiDiagnostic))
+    }
+
+    /// Handles the errors by emitting a [`tracing`] event for each one at the given level.
+    /// 
+    /// Each event has the error's [`Display`](core::fmt::Display) representation as its message,
+    /// and an `index` field with the error's position.
+    /// 
+    /// Requires the `tracing` feature.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// # use std::sync::{Arc, Mutex};
+    /// # use tracing::{Event, Level, Subscriber};
+    /// # use tracing_subscriber::{layer::{Context, Layer, SubscriberExt}};
+    /// # struct Capture(Arc<Mutex<Vec<Level>>>);
+    /// # impl<S: Subscriber> Layer<S> for Capture {
+    /// #     fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+    /// #         self.0.lock().unwrap().push(*event.metadata().level());
+    /// #     }
+    /// # }
+    /// # let levels = Arc::new(Mutex::new(vec![]));
+    /// # let subscriber = tracing_subscriber::registry().with(Capture(levels.clone()));
+    /// # tracing::subscriber::with_default(subscriber, || {
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2", "error 3"]);
+    /// errors.handle_with_tracing(Level::ERROR); // Emits 3 events
+    /// # });
+    /// # assert_eq!(*levels.lock().unwrap(), vec![Level::ERROR; 3]);
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn handle_with_tracing(self, level: tracing::Level)
+    where
+        E: Display,
+    {
+        for (index, error) in self.into_errors_iter().enumerate() {
+            crate::trace::emit_error_event(level, index, &error);
+        }
+    }
+
+    /// Handles the errors by emitting a [`log`] record for each one, with the given level and
+    /// target. Each record has the error's [`Display`](core::fmt::Display) representation as its
+    /// message.
+    /// 
+    /// Requires the `log` feature.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// # use std::sync::Mutex;
+    /// # use log::{Level, Log, Metadata, Record};
+    /// # static RECORDS: Mutex<Vec<(Level, String, String)>> = Mutex::new(vec![]);
+    /// # struct Capture;
+    /// # impl Log for Capture {
+    /// #     fn enabled(&self, _: &Metadata) -> bool { true }
+    /// #     fn log(&self, record: &Record) {
+    /// #         RECORDS.lock().unwrap().push((record.level(), record.target().to_owned(), record.args().to_string()));
+    /// #     }
+    /// #     fn flush(&self) {}
+    /// # }
+    /// # log::set_logger(&Capture).unwrap();
+    /// # log::set_max_level(log::LevelFilter::Trace);
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// errors.handle_with_log(Level::Warn, "parser");
+    /// # assert_eq!(*RECORDS.lock().unwrap(), vec![
+    /// #     (Level::Warn, "parser".to_owned(), "error 1".to_owned()),
+    /// #     (Level::Warn, "parser".to_owned(), "error 2".to_owned()),
+    /// # ]);
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn handle_with_log(self, level: log::Level, target: &str)
+    where
+        E: Display,
+    {
+        for error in self.into_errors_iter() {
+            log::log!(target: target, level, "{error}");
+        }
+    }
+
     /// Consumes this `ErrorSentinel` to create an [`ErrorSentinelIter`], enabling errors to be
     /// handled as an iterator.
     /// 
@@ -216,7 +863,8 @@ impl<E> ErrorSentinel<E> {
         let original_len = self.errors.as_ref().unwrap().len();
         ErrorSentinelIter {
             original_len,
-            iter: self.errors.take().unwrap().into_iter(), 
+            label: self.label,
+            iter: self.errors.take().unwrap().into_iter().peekable(),
         }
     }
 
@@ -232,6 +880,100 @@ impl<E> ErrorSentinel<E> {
         self.errors.as_ref().unwrap()
     }
 
+    /// Iterates over references to the errors, without considering them handled.
+    /// 
+    /// This is unlike [`into_errors_iter`], which consumes the sentinel and hands responsibility
+    /// for handling the errors to the iterator. Borrowing the errors with `iter` is useful for
+    /// inspecting them, such as logging them, before deciding how they should be handled. The
+    /// sentinel must still be handled afterwards.
+    /// 
+    /// [`into_errors_iter`]: ErrorSentinel::into_errors_iter
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["warning: unused", "error: missing semicolon"]);
+    /// for err in errors.iter() {
+    ///     println!("{err}");
+    /// }
+    /// 
+    /// if errors.iter().all(|err| err.starts_with("warning")) {
+    ///     errors.ignore();
+    /// } else {
+    ///     // Still need to handle the errors here!
+    ///     # errors.ignore();
+    /// }
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, E> {
+        self.peek().iter()
+    }
+
+    /// Joins the errors into a single string, separated by `sep`, without considering them
+    /// handled.
+    /// 
+    /// The [`Display`] implementation of `ErrorSentinel` is similar, but always separates errors
+    /// with newlines.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2", "error 3"]);
+    /// assert_eq!(errors.join("; "), "error 1; error 2; error 3");
+    /// assert_eq!(errors.join(" → "), "error 1 → error 2 → error 3");
+    /// assert_eq!(errors.to_string(), "error 1\nerror 2\nerror 3");
+    /// # errors.ignore();
+    /// 
+    /// let errors = ErrorSentinel::new(vec!["error 1"]);
+    /// assert_eq!(errors.join("; "), "error 1");
+    /// # errors.ignore();
+    /// 
+    /// let errors: ErrorSentinel<&str> = ErrorSentinel::empty();
+    /// assert_eq!(errors.join("; "), "");
+    /// assert_eq!(errors.to_string(), "");
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn join(&self, sep: &str) -> String
+    where
+        E: Display,
+    {
+        self.iter().map(|err| err.to_string()).collect::<Vec<_>>().join(sep)
+    }
+
+    /// Mutably access the list of errors, without considering them handled.
+    /// 
+    /// This is useful for editing errors in-place before handling them, such as attaching some
+    /// extra context to each one.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let mut errors = ErrorSentinel::new(vec!["error 1".to_owned(), "error 2".to_owned()]);
+    /// for err in errors.peek_mut() {
+    ///     *err = format!("main.rs: {err}");
+    /// }
+    /// 
+    /// assert_eq!(errors.peek(), &["main.rs: error 1", "main.rs: error 2"]);
+    /// # errors.ignore();
+    /// ```
+    /// 
+    /// Because this returns the underlying [`Vec`], it is also possible to add new errors through
+    /// it. This is fine, and equivalent to calling [`push_error`].
+    /// 
+    /// [`push_error`]: ErrorCollector::push_error
+    pub fn peek_mut(&mut self) -> &mut Vec<E> {
+        self.errors.as_mut().unwrap()
+    }
+
+    /// Shrinks the capacity of the underlying [`Vec`] of errors, like [`Outcome::shrink_to_fit`].
+    pub fn shrink_to_fit(&mut self) {
+        self.peek_mut().shrink_to_fit();
+    }
+
+    /// Computes summary statistics about the errors within this `ErrorSentinel`, without handling
+    /// them. See [`ErrorStats`] for an example.
+    #[must_use]
+    pub fn stats(&self) -> ErrorStats<'_, E> {
+        ErrorStats::new(self.peek())
+    }
+
     /// The number of errors within this `ErrorSentinel`.
     /// 
     /// ```
@@ -240,6 +982,7 @@ impl<E> ErrorSentinel<E> {
     /// assert_eq!(errors.len(), 2);
     /// # errors.ignore();
     /// ```
+    #[allow(clippy::len_without_is_empty)] // `any` is the way to check for errors
     pub fn len(&self) -> usize {
         self.errors.as_ref().unwrap().len()
     }
@@ -300,23 +1043,53 @@ impl<E> ErrorSentinel<E> {
             panic!("{}", msg)
         }
     }
+
+    /// Handles the errors by asserting that there aren't any, panicking otherwise.
+    /// 
+    /// This is equivalent to [`unwrap`], but with a clearer name. The panic message includes the
+    /// number of errors and their [`Debug`] representation.
+    /// 
+    /// [`unwrap`]: ErrorSentinel::unwrap
+    /// 
+    /// ```should_panic(expected = "expected no errors, found 2")
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// errors.assert_empty(); // Panics with: expected no errors, found 2: ["error 1", "error 2"]
+    /// ```
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::<String>::empty();
+    /// errors.assert_empty(); // OK
+    /// ```
+    #[track_caller]
+    pub fn assert_empty(mut self)
+    where E : Debug
+    {
+        self.handled = true;
+        if !self.peek().is_empty() {
+            let errors = self.errors.take().unwrap();
+            panic!("expected no errors, found {}: {:?}", errors.len(), errors)
+        }
+    }
 }
 
-impl ErrorSentinel<!> {
-    /// Constructs an `ErrorSentinel` which does not and will never contain errors, by using the
-    /// never type [`!`] as the error type.
+impl ErrorSentinel<Infallible> {
+    /// Constructs an `ErrorSentinel` which does not and will never contain errors, by using
+    /// [`Infallible`] as the error type.
     pub fn ok() -> Self {
         Self {
             errors: Some(vec![]),
             handled: false,
+            label: None,
         }
     }
 
-    /// An alias for [`ignore`] which is only available when the error type is the never type [`!`].
+    /// An alias for [`ignore`] which is only available when the error type is [`Infallible`].
     /// 
     /// In this case, an error can never occur, so it is safe to ignore errors. Using
     /// `safely_ignore` instead of `ignore` will signal to readers that this is a safe assumption,
-    /// and will cause a compile error if the error type ever changes from `!`.
+    /// and will cause a compile error if the error type ever changes from `Infallible`.
     /// 
     /// [`ignore`]: ErrorSentinel::ignore
     /// 
@@ -334,11 +1107,25 @@ impl<E> Drop for ErrorSentinel<E> {
     fn drop(&mut self) {
         // Let's not add on our own panic if the thread's already panicking. Things are bad enough!
         if !panicking() && !self.handled {
-            panic!("sentinel dropped without handling errors");
+            report_unhandled(UnhandledErrors::from_sentinel(self.len(), self.label));
         }
     }
 }
 
+impl<E: Display> Display for ErrorSentinel<E> {
+    /// Displays each error on its own line, without considering them handled. See also
+    /// [`ErrorSentinel::join`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, error) in self.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
 impl<E> ErrorCollector<E> for ErrorSentinel<E> {
     type WrappedInner = ();
 
@@ -346,9 +1133,23 @@ impl<E> ErrorCollector<E> for ErrorSentinel<E> {
         self.errors.as_mut().unwrap().push(error);
     }
 
-    fn propagate(self, other: &mut impl ErrorCollector<E>) -> Self::WrappedInner {
+    fn errors(&self) -> Option<&[E]> {
+        Some(self.peek())
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.peek().iter().for_each(f);
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
         ErrorSentinel::propagate(self, other);
     }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        for error in self.errors.as_mut().unwrap().drain(..) {
+            other.push_error(error);
+        }
+    }
 }
 
 /// An adapter for [`ErrorSentinel`] which implements [`Iterator`], so that errors can be handled
@@ -384,7 +1185,8 @@ impl<E> ErrorCollector<E> for ErrorSentinel<E> {
 /// ```
 pub struct ErrorSentinelIter<E> {
     original_len: usize,
-    iter: std::vec::IntoIter<E>,
+    label: Option<&'static str>,
+    iter: Peekable<vec::IntoIter<E>>,
 }
 
 impl<E> ErrorSentinelIter<E> {
@@ -402,6 +1204,64 @@ impl<E> ErrorSentinelIter<E> {
     pub fn is_handled(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns a reference to the next error, without advancing the iterator.
+    /// 
+    /// A peeked error is not considered handled until it is actually yielded by [`next`].
+    /// 
+    /// [`next`]: Iterator::next
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let mut error_iter = ErrorSentinel::new(vec!["error 1", "error 2"]).into_errors_iter();
+    /// 
+    /// assert_eq!(error_iter.peek(), Some(&"error 1"));
+    /// assert_eq!(error_iter.remaining(), 2);
+    /// 
+    /// assert_eq!(error_iter.next(), Some("error 1"));
+    /// assert_eq!(error_iter.next(), Some("error 2"));
+    /// assert_eq!(error_iter.peek(), None);
+    /// ```
+    pub fn peek(&mut self) -> Option<&E> {
+        self.iter.peek()
+    }
+
+    /// The number of errors which are yet to be handled. This is the same as [`len`], but reads
+    /// more clearly when deciding whether the iterator is done.
+    /// 
+    /// [`len`]: ExactSizeIterator::len
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let mut error_iter = ErrorSentinel::new(vec!["error 1", "error 2"]).into_errors_iter();
+    /// assert_eq!(error_iter.remaining(), 2);
+    /// 
+    /// error_iter.next();
+    /// assert_eq!(error_iter.remaining(), 1);
+    /// # error_iter.next();
+    /// ```
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    /// Handles every remaining error by calling a closure on it, exhausting the iterator.
+    /// 
+    /// This is equivalent to a `for` loop over the iterator, but guarantees that every error is
+    /// visited. With a `for` loop, it would be possible to accidentally `break` out early, which
+    /// would leave some errors unhandled and panic on drop.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["error 1", "error 2"]);
+    /// 
+    /// let mut messages = vec![];
+    /// errors.into_errors_iter().for_each_handled(|err| messages.push(format!("error: {err}")));
+    /// assert_eq!(messages, vec!["error: error 1", "error: error 2"]);
+    /// ```
+    pub fn for_each_handled(mut self, func: impl FnMut(E)) {
+        self.by_ref().for_each(func);
+    }
 }
 
 impl<E> Iterator for ErrorSentinelIter<E> {
@@ -410,6 +1270,10 @@ impl<E> Iterator for ErrorSentinelIter<E> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 impl<E> ExactSizeIterator for ErrorSentinelIter<E> {
@@ -422,11 +1286,21 @@ impl<E> Drop for ErrorSentinelIter<E> {
     fn drop(&mut self) {
         // Let's not add on our own panic if the thread's already panicking. Things are bad enough!
         if !panicking() && !self.is_handled() {
-            panic!(
-                "sentinel iterator dropped without handling all errors: {} out of {} error(s) unhandled",
-                self.len(),
-                self.original_len,
-            );
+            report_unhandled(UnhandledErrors::from_iter(self.len(), self.original_len, self.label));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::ErrorSentinel;
+
+    // Doctests don't check the message of `should_panic(expected = ...)`, but unit tests do
+    #[test]
+    #[should_panic(expected = r#"expected no errors, found 2: ["error 1", "error 2"]"#)]
+    fn assert_empty_message() {
+        ErrorSentinel::new(vec!["error 1", "error 2"]).assert_empty();
+    }
+}
@@ -0,0 +1,30 @@
+/// An error type which distinguishes fatal errors from non-fatal warnings.
+/// 
+/// Implementing this trait for an error type enables severity-aware queries on an [`Outcome`],
+/// such as [`is_fatal`] and [`warnings`], while still keeping all diagnostics in a single list.
+/// 
+/// [`Outcome`]: crate::Outcome
+/// [`is_fatal`]: crate::Outcome::is_fatal
+/// [`warnings`]: crate::Outcome::warnings
+/// 
+/// ```
+/// # use ocm::{Outcome, Severity};
+/// enum Diagnostic {
+///     UnusedVariable(String),
+///     MissingSemicolon,
+/// }
+/// 
+/// impl Severity for Diagnostic {
+///     fn is_fatal(&self) -> bool {
+///         matches!(self, Diagnostic::MissingSemicolon)
+///     }
+/// }
+/// 
+/// let o = Outcome::new_with_errors((), vec![Diagnostic::UnusedVariable("x".to_owned())]);
+/// assert!(!o.is_fatal());
+/// # o.finalize().1.ignore();
+/// ```
+pub trait Severity {
+    /// Returns `true` if this error is fatal, or `false` if it is only a warning.
+    fn is_fatal(&self) -> bool;
+}
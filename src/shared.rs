@@ -0,0 +1,177 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::{ErrorCollector, ErrorSentinel};
+
+/// An [`ErrorCollector`] with a single list of errors shared between many owners, which can be
+/// cloned and sent to other threads.
+/// 
+/// Each clone pushes into the same list. Once every other clone has been dropped, use
+/// [`into_inner`] on the last one to retrieve the errors in an [`ErrorSentinel`].
+/// 
+/// The shared list is itself an [`ErrorSentinel`], so the errors can't be lost by accident: if the
+/// last clone is dropped without calling [`into_inner`] or propagating it, it panics, even if no
+/// errors were pushed.
+/// 
+/// Since the errors are behind a lock, [`errors`](ErrorCollector::errors) returns `None`. Use
+/// [`for_each_error`](ErrorCollector::for_each_error) or [`snapshot`] instead. Propagating a
+/// `SharedCollector` drains the shared list, so other clones will see it empty afterwards.
+/// Propagating the last clone also counts as handling the errors.
+/// 
+/// Requires the `std` feature.
+/// 
+/// [`into_inner`]: SharedCollector::into_inner
+/// [`snapshot`]: SharedCollector::snapshot
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, SharedCollector};
+/// use std::thread;
+/// 
+/// let collector = SharedCollector::new();
+/// let workers = (0..4)
+///     .map(|worker| {
+///         let mut collector = collector.clone();
+///         thread::spawn(move || {
+///             for i in 0..25 {
+///                 collector.push_error(worker * 100 + i);
+///             }
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// 
+/// let errors = collector.into_inner().unwrap();
+/// let mut all = errors.handle(|errs| errs);
+/// all.sort();
+/// assert_eq!(all.len(), 100);
+/// all.dedup();
+/// assert_eq!(all.len(), 100);
+/// ```
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, SharedCollector};
+/// let mut a = SharedCollector::new();
+/// let b = a.clone();
+/// a.push_error("error 1");
+/// 
+/// let mut errors = vec![];
+/// a.propagate(&mut errors);
+/// b.propagate(&mut errors); // The last clone, so the errors are now handled
+/// assert_eq!(errors, vec!["error 1"]);
+/// ```
+/// 
+/// ```should_panic
+/// # use ocm::{ErrorCollector, SharedCollector};
+/// let mut a = SharedCollector::new();
+/// let b = a.clone();
+/// a.push_error("oh no!");
+/// 
+/// drop(a); // OK, `b` still holds the errors
+/// drop(b); // Panics!
+/// ```
+#[derive(Debug)]
+pub struct SharedCollector<E> {
+    errors: Arc<Mutex<ErrorSentinel<E>>>,
+}
+
+impl<E> SharedCollector<E> {
+    /// Creates a new, empty `SharedCollector`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { errors: Arc::new(Mutex::new(ErrorSentinel::empty())) }
+    }
+
+    /// Locks the shared list. A panic on another thread while holding the lock can't leave the
+    /// list in an inconsistent state, so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, ErrorSentinel<E>> {
+        self.errors.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns a copy of the errors which have been collected so far, by any clone.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, SharedCollector};
+    /// let mut a = SharedCollector::new();
+    /// let mut b = a.clone();
+    /// a.push_error("error 1");
+    /// b.push_error("error 2");
+    /// assert_eq!(a.snapshot(), vec!["error 1", "error 2"]);
+    /// # drop(b);
+    /// # a.into_inner().unwrap().ignore();
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<E>
+    where
+        E: Clone,
+    {
+        self.lock().peek().to_vec()
+    }
+
+    /// Consumes this `SharedCollector`, returning the errors in an [`ErrorSentinel`] if this is
+    /// the last remaining clone, or [`None`] if there are others.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, SharedCollector};
+    /// let mut a = SharedCollector::new();
+    /// let b = a.clone();
+    /// a.push_error("oh no!");
+    /// 
+    /// assert!(b.into_inner().is_none());
+    /// let errors = a.into_inner().unwrap();
+    /// assert_eq!(errors.peek(), &["oh no!"]);
+    /// # errors.ignore();
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Option<ErrorSentinel<E>> {
+        let errors = Arc::into_inner(self.errors)?;
+        Some(errors.into_inner().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl<E> Clone for SharedCollector<E> {
+    fn clone(&self) -> Self {
+        Self { errors: Arc::clone(&self.errors) }
+    }
+}
+
+impl<E> Default for SharedCollector<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> ErrorCollector<E> for SharedCollector<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        self.lock().push_error(error);
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.lock().iter().for_each(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        let errors = core::mem::take(self.lock().peek_mut());
+        for error in errors {
+            other.push_error(error);
+        }
+
+        // If this is the last clone, the now-empty sentinel must be handled too
+        if let Some(errors) = self.into_inner() {
+            errors.propagate(other);
+        }
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        let errors = core::mem::take(self.lock().peek_mut());
+        for error in errors {
+            other.push_error(error);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::ErrorCollector;
+
+/// An [`ErrorCollector`] which discards every error pushed into it.
+/// 
+/// This exists as an "escape hatch", like [`ErrorSentinel::ignore`], and its use is similarly
+/// strongly not recommended. Discarded errors are gone for good, and nothing will warn you about
+/// them. It is intended for benchmarks and genuinely best-effort code paths, where declaring once
+/// that errors are thrown away is clearer than ignoring them at many call sites.
+/// 
+/// A `SinkCollector` doesn't even count the errors pushed into it, so its
+/// [`error_count`](ErrorCollector::error_count) is always zero and
+/// [`has_errors`](ErrorCollector::has_errors) is always `false`. Generic code given a
+/// `SinkCollector` will believe that nothing has failed. Use [`CountingCollector`] if it matters
+/// whether anything failed.
+/// 
+/// [`ErrorSentinel::ignore`]: crate::ErrorSentinel::ignore
+/// [`CountingCollector`]: crate::CountingCollector
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome, SinkCollector};
+/// fn warm_cache(keys: &[&str], errors: &mut impl ErrorCollector<String>) {
+///     for key in keys {
+///         if key.is_empty() {
+///             errors.push_error("empty key".to_owned());
+///         }
+///     }
+/// }
+/// 
+/// // Cache warming is best-effort, so failures don't matter
+/// warm_cache(&["a", "", "b"], &mut SinkCollector);
+/// 
+/// let value = Outcome::new_with_errors(42, vec!["error 1", "error 2"]).propagate(&mut SinkCollector);
+/// assert_eq!(value, 42);
+/// 
+/// let mut sink = SinkCollector;
+/// sink.push_error("error 3");
+/// assert!(!ErrorCollector::<&str>::has_errors(&sink));
+/// # let _: &dyn ErrorCollector<&str> = &SinkCollector;
+/// ```
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct SinkCollector;
+
+impl<E> ErrorCollector<E> for SinkCollector {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, _error: E) {}
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
@@ -0,0 +1,163 @@
+use core::{fmt::Display, ops::Range};
+
+use crate::{ErrorSentinel, Outcome};
+
+/// An error paired with the span of source input which caused it, as a range of byte offsets.
+/// 
+/// Parsers often run sub-parsers over a slice of their input, which produce spans relative to the
+/// start of that slice. Use [`Outcome::offset_errors`] to shift them back to be relative to the
+/// whole input.
+/// 
+/// ```
+/// # use ocm::{Outcome, Spanned};
+/// let mut o = Outcome::new(());
+/// o.push_error_at(4..7, "unknown keyword");
+/// 
+/// let ((), errors) = o.finalize();
+/// assert_eq!(errors.peek(), &[Spanned::new(4..7, "unknown keyword")]);
+/// assert_eq!(errors.peek()[0].to_string(), "4..7: unknown keyword");
+/// # errors.ignore();
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Spanned<E> {
+    /// The span of input which caused the error.
+    pub span: Range<usize>,
+
+    /// The error.
+    pub error: E,
+}
+
+impl<E> Spanned<E> {
+    /// Wraps an error with a span.
+    #[must_use]
+    pub fn new(span: Range<usize>, error: E) -> Self {
+        Self { span, error }
+    }
+
+    /// Wraps an error with a zero-length span at a single offset, for errors which occur between
+    /// characters, like an unexpected end of input.
+    #[must_use]
+    pub fn at(offset: usize, error: E) -> Self {
+        Self::new(offset..offset, error)
+    }
+
+    /// Shifts the span forwards by `delta`.
+    pub fn offset(&mut self, delta: usize) {
+        self.span = (self.span.start + delta)..(self.span.end + delta);
+    }
+
+    /// Discards the span, returning the error.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for Spanned<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}..{}: {}", self.span.start, self.span.end, self.error)
+    }
+}
+
+impl<T, E> Outcome<T, Spanned<E>> {
+    /// Pushes an error with the given span.
+    pub fn push_error_at(&mut self, span: Range<usize>, error: E) {
+        self.push_error(Spanned::new(span, error));
+    }
+
+    /// Shifts the spans of all errors forwards by `delta`.
+    /// 
+    /// This is useful when a sub-parser operates on a slice of the input, so its spans are
+    /// relative to the start of that slice rather than the whole input.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, Outcome, Spanned};
+    /// // Parses a comma-separated list of numbers
+    /// fn parse_list(input: &str) -> Outcome<Vec<u32>, Spanned<String>> {
+    ///     Outcome::build(|errs| {
+    ///         let mut offset = 0;
+    ///         let mut numbers = vec![];
+    ///         for item in input.split(',') {
+    ///             let mut number = parse_number(item);
+    ///             number.offset_errors(offset);
+    ///             numbers.push(number.propagate(errs));
+    ///             offset += item.len() + 1;
+    ///         }
+    ///         numbers
+    ///     })
+    /// }
+    /// 
+    /// fn parse_number(input: &str) -> Outcome<u32, Spanned<String>> {
+    ///     Outcome::build(|errs| {
+    ///         match input.parse() {
+    ///             Ok(n) => n,
+    ///             Err(_) => {
+    ///                 errs.push_error(Spanned::new(0..input.len(), format!("bad number '{input}'")));
+    ///                 0
+    ///             }
+    ///         }
+    ///     })
+    /// }
+    /// 
+    /// // Lists nested inside brackets, parsed from the character after the bracket
+    /// let input = "[1,x,3,yy]";
+    /// let mut list = parse_list(&input[1..input.len() - 1]);
+    /// list.offset_errors(1);
+    /// 
+    /// let (numbers, errors) = list.finalize();
+    /// assert_eq!(numbers, vec![1, 0, 3, 0]);
+    /// assert_eq!(errors.peek(), &[
+    ///     Spanned::new(3..4, "bad number 'x'".to_owned()),
+    ///     Spanned::new(7..9, "bad number 'yy'".to_owned()),
+    /// ]);
+    /// assert_eq!(&input[7..9], "yy");
+    /// # errors.ignore();
+    /// ```
+    pub fn offset_errors(&mut self, delta: usize) {
+        for error in self.iter_errors_mut() {
+            error.offset(delta);
+        }
+    }
+
+    /// Replaces the span of each error with the result of a function.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, Spanned};
+    /// // Widen each span to include the surrounding quotes
+    /// let mut o = Outcome::new(());
+    /// o.push_error_at(3..5, "bad string");
+    /// o.map_spans(|span| (span.start - 1)..(span.end + 1));
+    /// 
+    /// let ((), errors) = o.finalize();
+    /// assert_eq!(errors.peek()[0].span, 2..6);
+    /// # errors.ignore();
+    /// ```
+    pub fn map_spans(&mut self, mut func: impl FnMut(Range<usize>) -> Range<usize>) {
+        for error in self.iter_errors_mut() {
+            error.span = func(error.span.clone());
+        }
+    }
+}
+
+impl<E> ErrorSentinel<Spanned<E>> {
+    /// Sorts the errors by the start of their span, and then by the end, so that they can be
+    /// reported in the order they appear in the input. The sort is stable, so errors with
+    /// identical spans keep the order they were pushed in.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, ErrorSentinel, Spanned};
+    /// let mut errors = ErrorSentinel::empty();
+    /// errors.push_error(Spanned::new(10..12, "c"));
+    /// errors.push_error(Spanned::new(2..8, "b"));
+    /// errors.push_error(Spanned::new(2..4, "a"));
+    /// errors.push_error(Spanned::new(10..12, "d"));
+    /// errors.sort_by_span();
+    /// 
+    /// let order = errors.peek().iter().map(|e| e.error).collect::<Vec<_>>();
+    /// assert_eq!(order, vec!["a", "b", "c", "d"]);
+    /// # errors.ignore();
+    /// ```
+    pub fn sort_by_span(&mut self) {
+        self.peek_mut().sort_by_key(|error| (error.span.start, error.span.end));
+    }
+}
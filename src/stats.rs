@@ -0,0 +1,103 @@
+#[cfg(feature = "std")]
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+/// Summary statistics about a collection of errors, created by [`Outcome::error_stats`] or
+/// [`ErrorSentinel::stats`].
+/// 
+/// The statistics are computed on demand from a borrowed view of the errors, so creating an
+/// `ErrorStats` doesn't consume or handle anything.
+/// 
+/// The statistics which group errors, `unique` and `count_by`, require the `std` feature.
+/// 
+/// [`Outcome::error_stats`]: crate::Outcome::error_stats
+/// [`ErrorSentinel::stats`]: crate::ErrorSentinel::stats
+/// 
+/// ```
+/// # #[cfg(feature = "std")] {
+/// # use ocm::Outcome;
+/// struct FileError { file: &'static str, kind: &'static str }
+/// 
+/// let o = Outcome::new_with_errors((), vec![
+///     FileError { file: "a.txt", kind: "syntax" },
+///     FileError { file: "a.txt", kind: "encoding" },
+///     FileError { file: "b.txt", kind: "syntax" },
+/// ]);
+/// 
+/// let stats = o.error_stats();
+/// let files = stats.count_by(|e| e.file).len();
+/// let kinds = stats.count_by(|e| e.kind).len();
+/// assert_eq!(
+///     format!("{} errors across {files} files, {kinds} distinct kinds", stats.total()),
+///     "3 errors across 2 files, 2 distinct kinds",
+/// );
+/// # o.finalize().1.ignore();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorStats<'a, E> {
+    errors: &'a [E],
+}
+
+impl<'a, E> ErrorStats<'a, E> {
+    /// Creates statistics for a slice of errors.
+    #[must_use]
+    pub fn new(errors: &'a [E]) -> Self {
+        Self { errors }
+    }
+
+    /// The total number of errors.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// The number of distinct errors, where equal errors are only counted once.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["a", "b", "a", "a"]);
+    /// assert_eq!(errors.stats().total(), 4);
+    /// assert_eq!(errors.stats().unique(), 2);
+    /// # errors.ignore();
+    /// 
+    /// let errors = ErrorSentinel::<&str>::empty();
+    /// assert_eq!(errors.stats().total(), 0);
+    /// assert_eq!(errors.stats().unique(), 0);
+    /// # errors.ignore();
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn unique(&self) -> usize
+    where
+        E: Eq + Hash,
+    {
+        self.errors.iter().collect::<HashSet<_>>().len()
+    }
+
+    /// Groups the errors by a key, and counts how many errors are in each group.
+    /// 
+    /// The number of groups is the length of the returned map.
+    /// 
+    /// ```
+    /// # use ocm::ErrorSentinel;
+    /// let errors = ErrorSentinel::new(vec!["a.txt: bad", "b.txt: bad", "a.txt: worse"]);
+    /// let by_file = errors.stats().count_by(|e| e.split(':').next().unwrap());
+    /// assert_eq!(by_file.len(), 2);
+    /// assert_eq!(by_file["a.txt"], 2);
+    /// assert_eq!(by_file["b.txt"], 1);
+    /// # errors.ignore();
+    /// 
+    /// let errors = ErrorSentinel::<&str>::empty();
+    /// assert!(errors.stats().count_by(|e| e.len()).is_empty());
+    /// # errors.ignore();
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn count_by<K: Eq + Hash>(&self, key: impl Fn(&E) -> K) -> HashMap<K, usize> {
+        let mut counts = HashMap::new();
+        for error in self.errors {
+            *counts.entry(key(error)).or_insert(0) += 1;
+        }
+        counts
+    }
+}
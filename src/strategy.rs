@@ -0,0 +1,50 @@
+use proptest::{collection::vec, strategy::Strategy};
+
+use crate::Outcome;
+
+/// A [`proptest`] strategy which generates `Outcome`s, using one strategy for the value and another
+/// for each of between zero and `max_errors` errors (inclusive).
+/// 
+/// Shrinking reduces the number of errors, towards an `Outcome` with no errors, as well as
+/// shrinking the value and each error.
+/// 
+/// Requires the `proptest` feature.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, outcome_strategy};
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+/// 
+/// let strategy = outcome_strategy(any::<u32>(), "[a-z]+", 3);
+/// let mut runner = TestRunner::default();
+/// 
+/// runner.run(&strategy, |o| {
+///     prop_assert!(o.len_errors() <= 3);
+///     prop_assert!(o.iter_errors().all(|e| !e.is_empty()));
+///     Ok(())
+/// }).unwrap();
+/// 
+/// // Sometimes generates `Outcome`s without any errors
+/// let clean = (0..100)
+///     .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+///     .filter(|o| o.is_success())
+///     .count();
+/// assert!(clean > 0);
+/// 
+/// // Shrinking stays within the bounds too
+/// let mut tree = strategy.new_tree(&mut runner).unwrap();
+/// while tree.simplify() {
+///     assert!(tree.current().len_errors() <= 3);
+/// }
+/// ```
+pub fn outcome_strategy<T, E>(
+    value: impl Strategy<Value = T>,
+    error: impl Strategy<Value = E>,
+    max_errors: usize,
+) -> impl Strategy<Value = Outcome<T, E>>
+where
+    T: core::fmt::Debug,
+    E: core::fmt::Debug,
+{
+    (value, vec(error, 0..=max_errors))
+        .prop_map(|(value, errors)| Outcome::new_with_errors(value, errors))
+}
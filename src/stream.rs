@@ -0,0 +1,43 @@
+use core::pin::pin;
+
+use alloc::vec;
+
+use futures::{Stream, StreamExt};
+
+use crate::{ErrorSentinel, Outcome};
+
+/// Awaits every [`Outcome`] produced by a [`Stream`], and combines them into a single `Outcome`.
+/// 
+/// This is the asynchronous equivalent of collecting an iterator of `Outcome`s with
+/// [`FromIterator`]. The values are collected into any type implementing [`FromIterator`], and the
+/// errors are accumulated in the order that their `Outcome`s arrived.
+/// 
+/// Requires the `futures` feature.
+/// 
+/// ```
+/// # use ocm::{Outcome, collect_outcome_stream};
+/// let stream = futures::stream::iter(vec![
+///     Outcome::new(1),
+///     Outcome::new_with_errors(2, vec!["error 1"]),
+///     Outcome::new_with_errors(3, vec!["error 2", "error 3"]),
+/// ]);
+/// 
+/// let o: Outcome<Vec<u32>, _> = futures::executor::block_on(collect_outcome_stream(stream));
+/// let (value, errors) = o.finalize();
+/// assert_eq!(value, vec![1, 2, 3]);
+/// assert_eq!(errors.peek(), &["error 1", "error 2", "error 3"]);
+/// # errors.ignore();
+/// ```
+pub async fn collect_outcome_stream<S, C, T, E>(stream: S) -> Outcome<C, E>
+where
+    S: Stream<Item = Outcome<T, E>>,
+    C: FromIterator<T>,
+{
+    let mut stream = pin!(stream);
+    let mut sentinel = ErrorSentinel::empty();
+    let mut values = vec![];
+    while let Some(outcome) = stream.next().await {
+        values.push(outcome.propagate(&mut sentinel));
+    }
+    sentinel.into_outcome(values.into_iter().collect())
+}
@@ -0,0 +1,61 @@
+use alloc::string::String;
+use core::{error::Error, fmt::Display};
+
+use crate::Outcome;
+
+/// A [`String`] which implements [`Error`], for using plain string errors with APIs which expect an
+/// error type, such as `Box<dyn Error>` or [`MultiError`]'s `Error` implementation.
+/// 
+/// The orphan rule prevents implementing `Error` for `String` directly. Convert an
+/// `Outcome<T, String>` with [`Outcome::into_error_results`].
+/// 
+/// [`MultiError`]: crate::MultiError
+/// 
+/// ```
+/// # use ocm::{Outcome, StringError};
+/// # use std::error::Error;
+/// fn check(input: &str) -> Outcome<usize, String> {
+///     let mut o = Outcome::new(input.len());
+///     if input.is_empty() {
+///         o.push_error("input is empty".to_owned());
+///     }
+///     o
+/// }
+/// 
+/// fn run() -> Result<usize, Box<dyn Error>> {
+///     Ok(check("").into_error_results().into_result_error()?)
+/// }
+/// 
+/// assert_eq!(run().unwrap_err().to_string(), "1 error(s):\n  - input is empty");
+/// ```
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StringError(pub String);
+
+impl Display for StringError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for StringError {}
+
+impl From<String> for StringError {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for StringError {
+    fn from(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<T> Outcome<T, String> {
+    /// Wraps each of the errors within this `Outcome` in a [`StringError`], so that they implement
+    /// [`Error`]. See the `StringError` docs for an example.
+    #[must_use]
+    pub fn into_error_results(self) -> Outcome<T, StringError> {
+        self.map_errors(StringError)
+    }
+}
@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+
+use crate::Outcome;
+
+/// A value which was obtained from an [`Outcome`] without any errors.
+/// 
+/// It isn't possible to implement [`TryFrom<Outcome<T, E>>`] for every `T`, so this newtype exists
+/// to allow an `Outcome` to be used with generic code which is bounded on [`TryInto`]. If the
+/// `Outcome` has any errors, the conversion fails with those errors, discarding the value.
+/// 
+/// ```
+/// # use ocm::{Outcome, Success};
+/// fn double(o: Outcome<u32, &'static str>) -> Result<u32, Vec<&'static str>> {
+///     let Success(value) = o.try_into()?;
+///     Ok(value * 2)
+/// }
+/// 
+/// assert_eq!(double(Outcome::new(21)), Ok(42));
+/// assert_eq!(
+///     double(Outcome::new_with_errors(21, vec!["error 1", "error 2"])),
+///     Err(vec!["error 1", "error 2"]),
+/// );
+/// ```
+/// 
+/// Like [`Outcome::into_result_vec`], the errors are returned as a plain [`Vec`] and are not
+/// protected by an [`ErrorSentinel`](crate::ErrorSentinel).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Success<T>(pub T);
+
+impl<T, E> TryFrom<Outcome<T, E>> for Success<T> {
+    type Error = Vec<E>;
+
+    fn try_from(outcome: Outcome<T, E>) -> Result<Self, Self::Error> {
+        outcome.into_result_vec().map(Success)
+    }
+}
+
+impl<T, E> From<Success<T>> for Outcome<T, E> {
+    /// Converts a [`Success`] back into an `Outcome`, which has no errors.
+    /// 
+    /// ```
+    /// # use ocm::{Outcome, Success};
+    /// let o: Outcome<_, &str> = Success(42).into();
+    /// assert!(o.is_success());
+    /// assert_eq!(o.unwrap(), 42);
+    /// ```
+    fn from(success: Success<T>) -> Self {
+        Outcome::new(success.0)
+    }
+}
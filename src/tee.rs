@@ -0,0 +1,152 @@
+use crate::ErrorCollector;
+
+/// An [`ErrorCollector`] which duplicates each error into two other collectors, for example to
+/// stream errors to a logger while also keeping them for a final report.
+/// 
+/// A clone of each error is pushed into the first collector, and the original into the second.
+/// Use [`into_parts`] to get the collectors back afterwards.
+/// 
+/// [`errors`](ErrorCollector::errors) and [`error_count`](ErrorCollector::error_count) describe
+/// the second collector. Propagating a `TeeCollector` propagates the second collector, returning
+/// the first.
+/// 
+/// [`into_parts`]: TeeCollector::into_parts
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, Outcome, TeeCollector};
+/// let mut tee = TeeCollector::new(vec![], ErrorSentinel::empty());
+/// Outcome::new_with_errors((), vec!["error 1", "error 2"]).propagate(&mut tee);
+/// tee.push_error("error 3");
+/// 
+/// let (log, report) = tee.into_parts();
+/// assert_eq!(log, vec!["error 1", "error 2", "error 3"]);
+/// assert_eq!(report.peek(), &["error 1", "error 2", "error 3"]);
+/// # report.ignore();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TeeCollector<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> TeeCollector<A, B> {
+    /// Creates a `TeeCollector` which pushes errors into both `first` and `second`.
+    #[must_use]
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consumes this `TeeCollector`, returning the two collectors.
+    #[must_use]
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    /// Creates a [`TeeMapCollector`] which pushes a projection of each error into `first`, and the
+    /// original error into `second`. This avoids cloning the whole error when only part of it is
+    /// needed.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, ErrorSentinel, TeeCollector};
+    /// struct Diagnostic { line: usize, message: String }
+    /// 
+    /// let mut tee = TeeCollector::tee_map(vec![], ErrorSentinel::empty(), |d: &Diagnostic| d.line);
+    /// tee.push_error(Diagnostic { line: 3, message: "oh no".to_owned() });
+    /// tee.push_error(Diagnostic { line: 7, message: "uh oh".to_owned() });
+    /// 
+    /// let (lines, diagnostics) = tee.into_parts();
+    /// assert_eq!(lines, vec![3, 7]);
+    /// assert_eq!(diagnostics.peek()[1].message, "uh oh");
+    /// # diagnostics.ignore();
+    /// ```
+    #[must_use]
+    pub fn tee_map<F>(first: A, second: B, func: F) -> TeeMapCollector<A, B, F> {
+        TeeMapCollector { first, second, func }
+    }
+}
+
+impl<E: Clone, A: ErrorCollector<E>, B: ErrorCollector<E>> ErrorCollector<E> for TeeCollector<A, B> {
+    type WrappedInner = A;
+
+    fn push_error(&mut self, error: E) {
+        self.first.push_error(error.clone());
+        self.second.push_error(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        self.second.errors()
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.second.for_each_error(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.second.error_count()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.second.propagate(other);
+        self.first
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.second.drain_into(other);
+    }
+}
+
+/// An [`ErrorCollector`] which pushes a projection of each error into one collector, and the
+/// original error into another. Created by [`TeeCollector::tee_map`].
+/// 
+/// Like [`TeeCollector`], [`errors`](ErrorCollector::errors) and
+/// [`error_count`](ErrorCollector::error_count) describe the second collector, and propagating
+/// propagates the second collector, returning the first.
+#[derive(Debug, Clone)]
+pub struct TeeMapCollector<A, B, F> {
+    first: A,
+    second: B,
+    func: F,
+}
+
+impl<A, B, F> TeeMapCollector<A, B, F> {
+    /// Consumes this `TeeMapCollector`, returning the two collectors.
+    #[must_use]
+    pub fn into_parts(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<E, P, A, B, F> ErrorCollector<E> for TeeMapCollector<A, B, F>
+where
+    A: ErrorCollector<P>,
+    B: ErrorCollector<E>,
+    F: FnMut(&E) -> P,
+{
+    type WrappedInner = A;
+
+    fn push_error(&mut self, error: E) {
+        self.first.push_error((self.func)(&error));
+        self.second.push_error(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        self.second.errors()
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.second.for_each_error(f);
+    }
+
+    fn error_count(&self) -> usize {
+        self.second.error_count()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.second.propagate(other);
+        self.first
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.second.drain_into(other);
+    }
+}
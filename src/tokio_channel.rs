@@ -0,0 +1,226 @@
+use tokio::sync::mpsc::{error::TrySendError, Sender, UnboundedSender};
+
+use crate::ErrorCollector;
+
+/// An [`ErrorCollector`] which sends each error through an unbounded [`tokio`] channel as soon as
+/// it is pushed, without blocking. This lets async tasks stream their errors to a central reporting
+/// task.
+/// 
+/// Errors from a single collector are received in the order they were pushed. Errors from
+/// different collectors sharing a channel may be interleaved.
+/// 
+/// Pushing an error panics if the receiving end of the channel has been closed, like the
+/// implementation for [`std::sync::mpsc::Sender`]. Errors are not retained by the collector, but
+/// it counts how many it has sent, so [`error_count`](ErrorCollector::error_count) and
+/// [`has_errors`](ErrorCollector::has_errors) still reflect them.
+/// 
+/// Requires the `tokio` feature.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, Outcome, TokioCollector};
+/// # tokio_test::block_on(async {
+/// let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+/// 
+/// let tasks = (0..3)
+///     .map(|task| {
+///         let mut collector = TokioCollector::new(sender.clone());
+///         tokio::spawn(async move {
+///             let o = Outcome::new_with_errors((), vec![(task, 1), (task, 2)]);
+///             o.propagate(&mut collector);
+///             assert_eq!(collector.error_count(), 2);
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// drop(sender);
+/// for task in tasks {
+///     task.await.unwrap();
+/// }
+/// 
+/// let mut errors = vec![];
+/// while let Some(error) = receiver.recv().await {
+///     errors.push(error);
+/// }
+/// assert_eq!(errors.len(), 6);
+/// 
+/// // Each task's errors arrive in order
+/// for task in 0..3 {
+///     let ours = errors.iter().filter(|(t, _)| *t == task).map(|(_, i)| *i).collect::<Vec<_>>();
+///     assert_eq!(ours, vec![1, 2]);
+/// }
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct TokioCollector<E> {
+    sender: UnboundedSender<E>,
+    sent: usize,
+}
+
+impl<E> TokioCollector<E> {
+    /// Creates a collector which sends errors through the given channel.
+    #[must_use]
+    pub fn new(sender: UnboundedSender<E>) -> Self {
+        Self { sender, sent: 0 }
+    }
+
+    /// Consumes this collector, returning the sender.
+    #[must_use]
+    pub fn into_inner(self) -> UnboundedSender<E> {
+        self.sender
+    }
+}
+
+impl<E> Clone for TokioCollector<E> {
+    /// Creates another collector sending through the same channel. The clone counts its own errors,
+    /// starting from zero.
+    /// 
+    /// ```
+    /// # use ocm::{ErrorCollector, TokioCollector};
+    /// let (sender, _receiver) = tokio::sync::mpsc::unbounded_channel();
+    /// let mut collector = TokioCollector::new(sender);
+    /// collector.push_error("error 1");
+    /// 
+    /// let clone = collector.clone();
+    /// assert_eq!(collector.error_count(), 1);
+    /// assert_eq!(clone.error_count(), 0);
+    /// ```
+    fn clone(&self) -> Self {
+        Self::new(self.sender.clone())
+    }
+}
+
+impl<E> ErrorCollector<E> for TokioCollector<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        if self.sender.send(error).is_err() {
+            panic!("error channel closed: receiver was dropped before all errors were sent");
+        }
+        self.sent += 1;
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn error_count(&self) -> usize {
+        self.sent
+    }
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
+
+/// What a [`BoundedTokioCollector`] does when its channel is full.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Panic, since errors would otherwise be lost.
+    Panic,
+
+    /// Discard the error, counting it in [`BoundedTokioCollector::discarded`].
+    Discard,
+}
+
+/// An [`ErrorCollector`] which sends each error through a bounded [`tokio`] channel as soon as it
+/// is pushed, without blocking.
+/// 
+/// Since pushing an error can't wait for space in the channel, an [`OverflowPolicy`] decides what
+/// happens when the channel is full. Pushing an error always panics if the receiving end of the
+/// channel has been closed.
+/// 
+/// Errors are not retained by the collector, but [`error_count`](ErrorCollector::error_count)
+/// counts every error which has been pushed, whether it was sent or discarded.
+/// 
+/// Requires the `tokio` feature.
+/// 
+/// ```
+/// # use ocm::{BoundedTokioCollector, ErrorCollector, OverflowPolicy};
+/// # tokio_test::block_on(async {
+/// let (sender, mut receiver) = tokio::sync::mpsc::channel(2);
+/// let mut collector = BoundedTokioCollector::new(sender, OverflowPolicy::Discard);
+/// 
+/// collector.push_error("error 1");
+/// collector.push_error("error 2");
+/// collector.push_error("error 3"); // Channel is full
+/// assert_eq!(collector.discarded(), 1);
+/// assert_eq!(collector.error_count(), 3);
+/// 
+/// drop(collector);
+/// assert_eq!(receiver.recv().await, Some("error 1"));
+/// assert_eq!(receiver.recv().await, Some("error 2"));
+/// assert_eq!(receiver.recv().await, None);
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct BoundedTokioCollector<E> {
+    sender: Sender<E>,
+    policy: OverflowPolicy,
+    sent: usize,
+    discarded: usize,
+}
+
+impl<E> BoundedTokioCollector<E> {
+    /// Creates a collector which sends errors through the given channel, handling a full channel
+    /// according to `policy`.
+    #[must_use]
+    pub fn new(sender: Sender<E>, policy: OverflowPolicy) -> Self {
+        Self { sender, policy, sent: 0, discarded: 0 }
+    }
+
+    /// The number of errors which have been discarded because the channel was full.
+    #[must_use]
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Consumes this collector, returning the sender.
+    #[must_use]
+    pub fn into_inner(self) -> Sender<E> {
+        self.sender
+    }
+}
+
+impl<E> Clone for BoundedTokioCollector<E> {
+    /// Creates another collector sending through the same channel, with the same
+    /// [`OverflowPolicy`]. The clone counts its own sent and discarded errors, starting from zero.
+    /// 
+    /// ```
+    /// # use ocm::{BoundedTokioCollector, ErrorCollector, OverflowPolicy};
+    /// let (sender, _receiver) = tokio::sync::mpsc::channel(1);
+    /// let mut collector = BoundedTokioCollector::new(sender, OverflowPolicy::Discard);
+    /// collector.push_error("error 1");
+    /// collector.push_error("error 2"); // Channel is full
+    /// 
+    /// let clone = collector.clone();
+    /// assert_eq!(collector.error_count(), 2);
+    /// assert_eq!(clone.error_count(), 0);
+    /// assert_eq!(clone.discarded(), 0);
+    /// ```
+    fn clone(&self) -> Self {
+        Self::new(self.sender.clone(), self.policy)
+    }
+}
+
+impl<E> ErrorCollector<E> for BoundedTokioCollector<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        match self.sender.try_send(error) {
+            Ok(()) => self.sent += 1,
+            Err(TrySendError::Full(_)) => match self.policy {
+                OverflowPolicy::Panic => panic!("error channel full"),
+                OverflowPolicy::Discard => self.discarded += 1,
+            },
+            Err(TrySendError::Closed(_)) =>
+                panic!("error channel closed: receiver was dropped before all errors were sent"),
+        }
+    }
+
+    fn for_each_error(&self, _f: &mut dyn FnMut(&E)) {}
+
+    fn error_count(&self) -> usize {
+        self.sent + self.discarded
+    }
+
+    fn propagate(self, _other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {}
+
+    fn drain_into(&mut self, _other: &mut dyn ErrorCollector<E>) {}
+}
@@ -0,0 +1,98 @@
+use core::fmt::Display;
+
+use tracing::Level;
+
+use crate::{ErrorCollector, ErrorSentinel};
+
+/// Emits an event for an error at a level only known at runtime, since [`tracing::event!`] needs
+/// a constant level.
+pub(crate) fn emit_error_event(level: Level, index: usize, error: &dyn Display) {
+    match level {
+        Level::ERROR => tracing::event!(Level::ERROR, index, "{error}"),
+        Level::WARN => tracing::event!(Level::WARN, index, "{error}"),
+        Level::INFO => tracing::event!(Level::INFO, index, "{error}"),
+        Level::DEBUG => tracing::event!(Level::DEBUG, index, "{error}"),
+        Level::TRACE => tracing::event!(Level::TRACE, index, "{error}"),
+    }
+}
+
+/// An [`ErrorCollector`] which emits a [`tracing`] event as each error is pushed, giving live
+/// visibility into long-running operations.
+/// 
+/// The errors are also retained in an [`ErrorSentinel`], so they must still be handled afterwards,
+/// either by propagating them elsewhere or with [`into_errors`]. Each event has the error's
+/// [`Display`] representation as its message, and an `index` field with the error's position.
+/// 
+/// Requires the `tracing` feature.
+/// 
+/// [`into_errors`]: TracingCollector::into_errors
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, TracingCollector};
+/// # use std::sync::{Arc, Mutex};
+/// # use tracing::{Event, Level, Subscriber};
+/// # use tracing_subscriber::{layer::{Context, Layer, SubscriberExt}};
+/// # struct Capture(Arc<Mutex<Vec<Level>>>);
+/// # impl<S: Subscriber> Layer<S> for Capture {
+/// #     fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+/// #         self.0.lock().unwrap().push(*event.metadata().level());
+/// #     }
+/// # }
+/// # let levels = Arc::new(Mutex::new(vec![]));
+/// # let subscriber = tracing_subscriber::registry().with(Capture(levels.clone()));
+/// # tracing::subscriber::with_default(subscriber, || {
+/// let mut collector = TracingCollector::new(Level::WARN);
+/// collector.push_error("error 1"); // Emits an event immediately
+/// collector.push_error("error 2");
+/// 
+/// let mut errors = ErrorSentinel::empty();
+/// collector.propagate(&mut errors);
+/// assert_eq!(errors.peek(), &["error 1", "error 2"]);
+/// # errors.ignore();
+/// # });
+/// # assert_eq!(*levels.lock().unwrap(), vec![Level::WARN, Level::WARN]);
+/// ```
+#[derive(Debug)]
+pub struct TracingCollector<E> {
+    level: Level,
+    errors: ErrorSentinel<E>,
+}
+
+impl<E> TracingCollector<E> {
+    /// Creates a new, empty `TracingCollector` which emits events at the given level.
+    #[must_use]
+    pub fn new(level: Level) -> Self {
+        Self { level, errors: ErrorSentinel::empty() }
+    }
+
+    /// Consumes this collector, returning the errors which were pushed into it.
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn into_errors(self) -> ErrorSentinel<E> {
+        self.errors
+    }
+}
+
+impl<E: Display> ErrorCollector<E> for TracingCollector<E> {
+    type WrappedInner = ();
+
+    fn push_error(&mut self, error: E) {
+        emit_error_event(self.level, self.errors.len(), &error);
+        self.errors.push_error(error);
+    }
+
+    fn errors(&self) -> Option<&[E]> {
+        Some(self.errors.peek())
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.errors.iter().for_each(f);
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        self.errors.propagate(other);
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        self.errors.drain_into(other);
+    }
+}
@@ -0,0 +1,180 @@
+use core::fmt::Display;
+use std::backtrace::Backtrace;
+
+use crate::{ErrorCollector, ErrorSentinel, Outcome};
+
+/// An error paired with a [`Backtrace`] captured when it was created, for tracking down which of
+/// many call sites produced it.
+/// 
+/// The backtrace is captured with [`Backtrace::capture`], so it is only actually collected when
+/// the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables enable it. Otherwise,
+/// capturing is cheap and the backtrace's status will be
+/// [`Disabled`](std::backtrace::BacktraceStatus::Disabled).
+/// 
+/// The [`Display`] implementation only displays the inner error. Use [`backtrace`] to print the
+/// backtrace too.
+/// 
+/// Requires the `backtrace` feature.
+/// 
+/// [`backtrace`]: Traced::backtrace
+/// 
+/// ```
+/// # use ocm::{Outcome, Traced};
+/// use std::backtrace::BacktraceStatus;
+/// 
+/// std::env::set_var("RUST_LIB_BACKTRACE", "1");
+/// 
+/// let mut o = Outcome::new(42);
+/// o.push_error_traced("something went wrong");
+/// 
+/// let (_, errors) = o.finalize();
+/// let traced = &errors.peek()[0];
+/// assert_eq!(traced.to_string(), "something went wrong");
+/// assert_eq!(traced.backtrace().status(), BacktraceStatus::Captured);
+/// # errors.ignore();
+/// ```
+#[derive(Debug)]
+pub struct Traced<E> {
+    error: E,
+    backtrace: Backtrace,
+}
+
+impl<E> Traced<E> {
+    /// Wraps an error, capturing a backtrace of the caller.
+    #[must_use]
+    pub fn new(error: E) -> Self {
+        Self { error, backtrace: Backtrace::capture() }
+    }
+
+    /// The error.
+    #[must_use]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// The backtrace captured when the error was created.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Discards the backtrace, returning the error.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for Traced<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<E: core::error::Error> core::error::Error for Traced<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<T, E> Outcome<T, Traced<E>> {
+    /// Pushes an error, along with a backtrace captured at this point. See the [`Traced`] docs
+    /// for an example.
+    pub fn push_error_traced(&mut self, error: E) {
+        self.push_error(Traced::new(error));
+    }
+}
+
+/// An [`ErrorCollector`] which wraps another collector of [`Traced`] errors, so that plain errors
+/// pushed into it have a backtrace captured automatically.
+/// 
+/// This means code which accepts any `ErrorCollector<E>` can be given a `TracedCollector`, without
+/// needing to know about backtraces.
+/// 
+/// Since the inner collector stores [`Traced`] errors, [`errors`](ErrorCollector::errors) returns
+/// `None`, but [`for_each_error`](ErrorCollector::for_each_error) visits each error without its
+/// backtrace. Use [`inner`](TracedCollector::inner) to access the backtraces too. Propagating a
+/// `TracedCollector` into a collector of plain errors discards the backtraces.
+/// 
+/// Requires the `backtrace` feature.
+/// 
+/// ```
+/// # use ocm::{ErrorCollector, ErrorSentinel, Traced, TracedCollector};
+/// fn parse(input: &str, errors: &mut impl ErrorCollector<String>) {
+///     if input.is_empty() {
+///         errors.push_error("empty input".to_owned());
+///     }
+/// }
+/// 
+/// let mut collector = TracedCollector::new(ErrorSentinel::<Traced<String>>::empty());
+/// parse("", &mut collector);
+/// assert_eq!(collector.error_count(), 1);
+/// 
+/// let mut messages = vec![];
+/// collector.for_each_error(&mut |error: &String| messages.push(error.clone()));
+/// assert_eq!(messages, vec!["empty input"]);
+/// 
+/// let errors = collector.into_inner();
+/// assert_eq!(errors.peek()[0].error(), "empty input");
+/// # errors.ignore();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TracedCollector<C> {
+    inner: C,
+}
+
+impl<C> TracedCollector<C> {
+    /// Wraps a collector, so that errors pushed into it have a backtrace captured.
+    #[must_use]
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// The inner collector, which holds the [`Traced`] errors.
+    #[must_use]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Consumes this `TracedCollector`, returning the inner collector.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<E, C: ErrorCollector<Traced<E>>> ErrorCollector<E> for TracedCollector<C> {
+    type WrappedInner = C::WrappedInner;
+
+    fn push_error(&mut self, error: E) {
+        self.inner.push_error(Traced::new(error));
+    }
+
+    fn for_each_error(&self, f: &mut dyn FnMut(&E)) {
+        self.inner.for_each_error(&mut |traced| f(traced.error()));
+    }
+
+    fn error_count(&self) -> usize {
+        self.inner.error_count()
+    }
+
+    fn propagate(self, other: &mut (impl ErrorCollector<E> + ?Sized)) -> Self::WrappedInner {
+        let mut traced = ErrorSentinel::empty();
+        let wrapped = self.inner.propagate(&mut traced);
+        traced.handle(|errors| {
+            for error in errors {
+                other.push_error(error.into_inner());
+            }
+        });
+        wrapped
+    }
+
+    fn drain_into(&mut self, other: &mut dyn ErrorCollector<E>) {
+        let mut traced = ErrorSentinel::empty();
+        self.inner.drain_into(&mut traced);
+        traced.handle(|errors| {
+            for error in errors {
+                other.push_error(error.into_inner());
+            }
+        });
+    }
+}
@@ -0,0 +1,62 @@
+use core::{convert::Infallible, ops::{ControlFlow, FromResidual, Residual, Try}};
+
+use alloc::vec;
+
+use crate::{ErrorSentinel, Outcome};
+
+/// Enables the `?` operator on an `Outcome`. See the "The `?` operator" section of the [`Outcome`]
+/// docs for details.
+/// 
+/// [`Try`] requires that an `Outcome` can be created from its own residual, which is only possible
+/// when its value type has a [`Default`] - that requirement comes from the [`FromResidual`]
+/// implementation below.
+impl<T, E> Try for Outcome<T, E>
+where
+    Self: FromResidual<ErrorSentinel<E>>,
+{
+    type Output = T;
+    type Residual = ErrorSentinel<E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Outcome::new(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.into_result() {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(errors) => ControlFlow::Break(errors),
+        }
+    }
+}
+
+impl<T, E> Residual<T> for ErrorSentinel<E>
+where
+    Outcome<T, E>: FromResidual<Self>,
+{
+    type TryType = Outcome<T, E>;
+}
+
+/// Enables the `?` operator to return errors from a function which returns an `Outcome`. The
+/// returned `Outcome` has the [`Default`] value of its value type.
+impl<T: Default, E> FromResidual<ErrorSentinel<E>> for Outcome<T, E> {
+    fn from_residual(residual: ErrorSentinel<E>) -> Self {
+        residual.into_outcome(T::default())
+    }
+}
+
+/// Enables the `?` operator to return errors from a function which returns a [`Result`] with an
+/// [`ErrorSentinel`] as its error type, like the one returned by [`Outcome::into_result`].
+impl<T, E> FromResidual<ErrorSentinel<E>> for Result<T, ErrorSentinel<E>> {
+    fn from_residual(residual: ErrorSentinel<E>) -> Self {
+        Err(residual)
+    }
+}
+
+/// Enables the `?` operator on a [`Result`] in a function which returns an `Outcome`. The error is
+/// returned as the only error of the `Outcome`, which has the [`Default`] value of its value type.
+impl<T: Default, E> FromResidual<Result<Infallible, E>> for Outcome<T, E> {
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        let Err(error) = residual;
+        Outcome::new_with_errors(T::default(), vec![error])
+    }
+}
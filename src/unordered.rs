@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use crate::Outcome;
+
+/// Wraps an [`Outcome`] so that its [`PartialEq`], [`Eq`] and [`Hash`] implementations ignore the
+/// order of its errors, treating them as a multiset.
+/// 
+/// The derived implementations on `Outcome` are strict about the order of errors, which is a
+/// problem when errors are produced in a nondeterministic order but the `Outcome` needs to be used
+/// as a key in a `HashMap` or `HashSet`. `UnorderedErrors` keeps those implementations intact,
+/// while offering set semantics when they're needed.
+/// 
+/// Each comparison or hash sorts references to the errors first, which takes `O(n log n)` time and
+/// allocates a temporary [`Vec`]. To compare many times, consider sorting the errors once
+/// with [`Outcome::errors_mut`] instead.
+/// 
+/// ```
+/// # use ocm::{Outcome, UnorderedErrors};
+/// # use std::collections::HashSet;
+/// let a = Outcome::new_with_errors(42, vec!["error 1", "error 2"]);
+/// let b = Outcome::new_with_errors(42, vec!["error 2", "error 1"]);
+/// assert_ne!(a, b);
+/// 
+/// let mut seen = HashSet::new();
+/// seen.insert(UnorderedErrors(a));
+/// assert!(seen.contains(&UnorderedErrors(b)));
+/// 
+/// let c = Outcome::new_with_errors(42, vec!["error 1", "error 1"]);
+/// assert!(!seen.contains(&UnorderedErrors(c)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnorderedErrors<T, E>(pub Outcome<T, E>);
+
+impl<T, E> UnorderedErrors<T, E> {
+    /// Returns the wrapped `Outcome`.
+    #[must_use]
+    pub fn into_inner(self) -> Outcome<T, E> {
+        self.0
+    }
+
+    /// References to the errors, in sorted order.
+    fn sorted_errors(&self) -> Vec<&E>
+    where
+        E: Ord,
+    {
+        let mut errors = self.0.iter_errors().collect::<Vec<_>>();
+        errors.sort();
+        errors
+    }
+}
+
+impl<T: PartialEq, E: Ord> PartialEq for UnorderedErrors<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.value() == other.0.value() && self.sorted_errors() == other.sorted_errors()
+    }
+}
+
+impl<T: Eq, E: Ord> Eq for UnorderedErrors<T, E> {}
+
+impl<T: Hash, E: Ord + Hash> Hash for UnorderedErrors<T, E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.value().hash(state);
+        self.sorted_errors().hash(state);
+    }
+}
@@ -0,0 +1,58 @@
+use core::{error::Error, fmt::{Debug, Display}};
+
+use crate::ErrorSentinel;
+
+/// The error returned by [`ErrorSentinel::handle_write_fmt`] when writing one of the errors fails.
+#[cfg_attr(feature = "std", doc = "Also returned by [`ErrorSentinel::handle_write`].")]
+/// 
+/// The errors which were not written, including the one which failed, are not lost. They are kept
+/// in a new [`ErrorSentinel`], which can be retrieved with [`into_unwritten`] and must be handled
+/// as usual.
+/// 
+/// [`into_unwritten`]: WriteError::into_unwritten
+#[derive(Debug)]
+pub struct WriteError<E, W> {
+    source: W,
+    written: usize,
+    unwritten: ErrorSentinel<E>,
+}
+
+impl<E, W> WriteError<E, W> {
+    pub(crate) fn new(source: W, written: usize, unwritten: ErrorSentinel<E>) -> Self {
+        Self { source, written, unwritten }
+    }
+
+    /// The error which occurred while writing.
+    pub fn write_error(&self) -> &W {
+        &self.source
+    }
+
+    /// The number of errors which were successfully written before the failure.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Consumes this `WriteError`, returning an [`ErrorSentinel`] with the errors which were not
+    /// written, starting with the one which failed.
+    #[must_use = "discarding the `ErrorSentinel` will panic immediately"]
+    pub fn into_unwritten(self) -> ErrorSentinel<E> {
+        self.unwritten
+    }
+}
+
+impl<E, W: Display> Display for WriteError<E, W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "failed to write errors: {} ({} error(s) unwritten)",
+            self.source,
+            self.unwritten.len(),
+        )
+    }
+}
+
+impl<E: Debug, W: Error + 'static> Error for WriteError<E, W> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+//! The exiting handlers end the process, so each case is run in a child process: this test binary
+//! re-executes itself, running only `child`, with the case to run in an environment variable.
+
+use std::process::{Command, ExitCode, Termination};
+
+use ocm::{ErrorSentinel, Outcome};
+
+const CASE_VAR: &str = "OCM_EXIT_TEST_CASE";
+
+/// Runs in the child process, does nothing when run normally.
+#[test]
+fn child() {
+    let Ok(case) = std::env::var(CASE_VAR) else { return };
+    match case.as_str() {
+        "handle_exit" => ErrorSentinel::new(vec!["error 1", "error 2"]).handle_exit(3),
+        "exit_if_errors" => ErrorSentinel::new(vec!["oh no"]).exit_if_errors(4),
+        "exit_if_errors_empty" => ErrorSentinel::<&str>::empty().exit_if_errors(4),
+        "finalize_or_exit" => {
+            let value = Outcome::new_with_errors(42, vec!["error 1"]).finalize_or_exit(5);
+            eprintln!("unreachable: {value}");
+        }
+        "finalize_or_exit_empty" => {
+            let value = Outcome::<_, &str>::new(42).finalize_or_exit(5);
+            eprintln!("value: {value}");
+        }
+        "termination" => {
+            let code = Outcome::new_with_errors((), vec!["error 1", "error 2"]).report();
+            std::process::exit(if code == ExitCode::FAILURE { 1 } else { 0 });
+        }
+        _ => panic!("unknown case {case}"),
+    }
+    std::process::exit(0);
+}
+
+/// Runs `case` in a child process, returning its exit code and standard error.
+fn run(case: &str) -> (Option<i32>, String) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "child", "--nocapture", "--test-threads=1"])
+        .env(CASE_VAR, case)
+        .output()
+        .unwrap();
+    (output.status.code(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn handle_exit() {
+    assert_eq!(run("handle_exit"), (Some(3), "error 1\nerror 2\n2 error(s) occurred\n".to_owned()));
+}
+
+#[test]
+fn exit_if_errors() {
+    assert_eq!(run("exit_if_errors"), (Some(4), "oh no\n1 error(s) occurred\n".to_owned()));
+    assert_eq!(run("exit_if_errors_empty"), (Some(0), String::new()));
+}
+
+#[test]
+fn finalize_or_exit() {
+    assert_eq!(run("finalize_or_exit"), (Some(5), "error 1\n1 error(s) occurred\n".to_owned()));
+    assert_eq!(run("finalize_or_exit_empty"), (Some(0), "value: 42\n".to_owned()));
+}
+
+#[test]
+fn termination() {
+    assert_eq!(run("termination"), (Some(1), "error 1\nerror 2\n2 error(s) occurred\n".to_owned()));
+}
@@ -0,0 +1,8 @@
+#![cfg(feature = "try_trait")]
+
+#[test]
+fn try_operator() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/try_trait/pass_*.rs");
+    cases.compile_fail("tests/try_trait/fail_*.rs");
+}
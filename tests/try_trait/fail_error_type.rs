@@ -0,0 +1,8 @@
+use ocm::Outcome;
+
+// Errors can't be converted between types implicitly
+fn convert(o: Outcome<u32, String>) -> Outcome<u32, &'static str> {
+    Outcome::new(o?)
+}
+
+fn main() {}
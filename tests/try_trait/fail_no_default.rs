@@ -0,0 +1,11 @@
+use ocm::Outcome;
+
+struct NoDefault;
+
+// The returned `Outcome` needs a default value to return alongside the errors
+fn make(o: Outcome<u32, String>) -> Outcome<NoDefault, String> {
+    o?;
+    Outcome::new(NoDefault)
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ocm::Outcome;
+
+// Errors can't be propagated into an `Option`, since they would be dropped
+fn first_value(o: Outcome<u32, String>) -> Option<u32> {
+    Some(o?)
+}
+
+fn main() {}
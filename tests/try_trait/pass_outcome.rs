@@ -0,0 +1,20 @@
+use ocm::Outcome;
+
+fn parse_num(s: &str) -> Outcome<u32, String> {
+    Outcome::from_result(s.parse().map_err(|_| format!("not a number: {s}")))
+}
+
+fn add(a: &str, b: &str) -> Outcome<u32, String> {
+    let a = parse_num(a)?;
+    let b = parse_num(b)?;
+    Outcome::new(a + b)
+}
+
+fn main() {
+    assert_eq!(add("1", "2").unwrap(), 3);
+
+    let (value, errors) = add("1", "y").finalize();
+    assert_eq!(value, 0);
+    assert_eq!(errors.peek(), &["not a number: y"]);
+    errors.ignore();
+}
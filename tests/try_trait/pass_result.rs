@@ -0,0 +1,17 @@
+use ocm::{ErrorSentinel, Outcome};
+
+fn parse_num(s: &str) -> Outcome<u32, String> {
+    Outcome::from_result(s.parse().map_err(|_| format!("not a number: {s}")))
+}
+
+fn add(a: &str, b: &str) -> Result<u32, ErrorSentinel<String>> {
+    Ok(parse_num(a)? + parse_num(b)?)
+}
+
+fn main() {
+    assert_eq!(add("1", "2").unwrap(), 3);
+
+    let errors = add("x", "2").unwrap_err();
+    assert_eq!(errors.peek(), &["not a number: x"]);
+    errors.ignore();
+}
@@ -0,0 +1,16 @@
+use ocm::Outcome;
+use std::num::ParseIntError;
+
+fn double(s: &str) -> Outcome<u32, ParseIntError> {
+    let n: u32 = s.parse()?;
+    Outcome::new(n * 2)
+}
+
+fn main() {
+    assert_eq!(double("21").unwrap(), 42);
+
+    let (value, errors) = double("x").finalize();
+    assert_eq!(value, 0);
+    assert_eq!(errors.len(), 1);
+    errors.ignore();
+}